@@ -863,6 +863,18 @@ fn swap(bcx: &mut FunctionBuilder<'_>, v: Value) -> Value {
             ret.write_cvalue(fx, val);
         };
 
+        ptr_addr, (v ptr) {
+            let usize_layout = fx.layout_of(fx.tcx.types.usize);
+            ret.write_cvalue(fx, CValue::by_val(ptr, usize_layout));
+        };
+
+        ptr_same_allocation, (c _a, c _b) {
+            // Like in `rustc_codegen_ssa`: without provenance, the pointers could always
+            // share an allocation.
+            let val = CValue::const_val(fx, ret.layout(), ty::ScalarInt::from(true));
+            ret.write_cvalue(fx, val);
+        };
+
         ptr_guaranteed_eq, (c a, c b) {
             let val = crate::num::codegen_ptr_binop(fx, BinOp::Eq, a, b);
             ret.write_cvalue(fx, val);
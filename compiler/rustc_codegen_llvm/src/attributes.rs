@@ -8,14 +8,17 @@
 use rustc_data_structures::small_c_str::SmallCStr;
 use rustc_hir::def_id::DefId;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
-use rustc_middle::ty::layout::HasTyCtxt;
+use rustc_middle::mir::mono::MonoItem;
+use rustc_middle::mir::TerminatorKind;
+use rustc_middle::ty::layout::{FnAbiExt, HasTyCtxt};
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_session::config::OptLevel;
 use rustc_session::Session;
 use rustc_target::spec::abi::Abi;
-use rustc_target::spec::{SanitizerSet, StackProbeType};
+use rustc_target::spec::{FramePointer, SanitizerSet, StackProbeType};
 
+use crate::abi::FnAbi;
 use crate::attributes;
 use crate::llvm::AttributePlace::Function;
 use crate::llvm::{self, Attribute};
@@ -71,15 +74,42 @@ fn naked(val: &'ll Value, is_naked: bool) {
     Attribute::Naked.toggle_llfn(Function, val, is_naked);
 }
 
-pub fn set_frame_pointer_elimination(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
-    if cx.sess().must_not_eliminate_frame_pointers() {
-        llvm::AddFunctionAttrStringValue(
-            llfn,
-            llvm::AttributePlace::Function,
-            cstr!("frame-pointer"),
-            cstr!("all"),
+/// Tell LLVM whether `llfn` keeps its frame pointer. `can_unwind` only matters for
+/// `-C force-frame-pointers=unwind`, and `is_leaf` only for `-C force-frame-pointers=leaf`.
+pub fn set_frame_pointer_elimination(
+    cx: &CodegenCx<'ll, '_>,
+    llfn: &'ll Value,
+    can_unwind: bool,
+    is_leaf: bool,
+) {
+    let (policy, source) = cx.sess().frame_pointer_with_source();
+    let value = match policy {
+        FramePointer::Always => Some(cstr!("all")),
+        FramePointer::NonLeaf => Some(cstr!("non-leaf")),
+        FramePointer::Unwind if can_unwind => Some(cstr!("all")),
+        // LLVM has no "leaf only" mode, so leaf functions are asked to keep all of theirs.
+        FramePointer::Leaf if is_leaf => Some(cstr!("all")),
+        FramePointer::Unwind | FramePointer::Leaf | FramePointer::MayOmit => None,
+    };
+    if cx.sess().opts.debugging_opts.print_frame_pointer_decisions {
+        println!(
+            "frame-pointer: {}: {} (policy `{}` from {})",
+            String::from_utf8_lossy(llvm::get_value_name(llfn)),
+            value.map_or("unset".into(), |value| value.to_string_lossy()),
+            policy,
+            source,
         );
     }
+    let value = match value {
+        Some(value) => value,
+        None => return,
+    };
+    llvm::AddFunctionAttrStringValue(
+        llfn,
+        llvm::AttributePlace::Function,
+        cstr!("frame-pointer"),
+        value,
+    );
 }
 
 /// Tell LLVM what instrument function to insert.
@@ -205,6 +235,21 @@ pub(crate) fn default_optimisation_attrs(sess: &Session, llfn: &'ll Value) {
     }
 }
 
+/// Whether the MIR of `instance` contains no calls, including the implicit ones made by drops
+/// and by the panics of failed assertions. Calls that end up inlined or lowered to plain
+/// instructions still count, so this errs on the side of treating functions as non-leaf.
+fn is_leaf_instance(tcx: TyCtxt<'tcx>, instance: ty::Instance<'tcx>) -> bool {
+    tcx.instance_mir(instance.def).basic_blocks().iter().all(|block| {
+        !matches!(
+            block.terminator().kind,
+            TerminatorKind::Call { .. }
+                | TerminatorKind::Drop { .. }
+                | TerminatorKind::DropAndReplace { .. }
+                | TerminatorKind::Assert { .. }
+        )
+    })
+}
+
 /// Composite function which sets LLVM attributes for function depending on its AST (`#[attribute]`)
 /// attributes.
 pub fn from_fn_attrs(cx: &CodegenCx<'ll, 'tcx>, llfn: &'ll Value, instance: ty::Instance<'tcx>) {
@@ -255,8 +300,19 @@ pub fn from_fn_attrs(cx: &CodegenCx<'ll, 'tcx>, llfn: &'ll Value, instance: ty::
         attributes::emit_uwtable(llfn, true);
     }
 
+    // Computing the ABI is only worth it when frame pointers depend on unwinding.
+    let can_unwind = cx.sess().frame_pointer() == FramePointer::Unwind
+        && FnAbi::of_instance(cx, instance, &[]).can_unwind;
+
+    // Likewise, only look for calls in the MIR when frame pointers depend on them. Functions
+    // that are only declared here, like foreign items and functions of other crates or codegen
+    // units, may have no MIR, and their attributes do not affect their definition anyway.
+    let is_leaf = cx.sess().frame_pointer() == FramePointer::Leaf
+        && cx.codegen_unit().items().contains_key(&MonoItem::Fn(instance))
+        && is_leaf_instance(cx.tcx, instance);
+
     // FIXME: none of these three functions interact with source level attributes.
-    set_frame_pointer_elimination(cx, llfn);
+    set_frame_pointer_elimination(cx, llfn, can_unwind, is_leaf);
     set_instrument_function(cx, llfn);
     set_probestack(cx, llfn);
 
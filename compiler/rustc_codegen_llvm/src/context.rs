@@ -411,7 +411,9 @@ fn used_statics(&self) -> &RefCell<Vec<&'ll Value>> {
     }
 
     fn set_frame_pointer_elimination(&self, llfn: &'ll Value) {
-        attributes::set_frame_pointer_elimination(self, llfn)
+        // The shims that go through here (the `main` wrapper and `try`) sit on unwinding paths,
+        // and both call into other functions.
+        attributes::set_frame_pointer_elimination(self, llfn, true, false)
     }
 
     fn apply_target_cpu_attr(&self, llfn: &'ll Value) {
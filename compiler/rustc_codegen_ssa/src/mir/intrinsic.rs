@@ -407,6 +407,25 @@ pub fn codegen_intrinsic_call(
                     );
                 };
 
+                // Types that cannot be used atomically at all are reported below.
+                if split[1] != "fence" && split[1] != "singlethreadfence" {
+                    let ty = substs.type_at(0);
+                    let is_atomic_ty =
+                        int_type_width_signed(ty, bx.tcx()).is_some() || ty.is_unsafe_ptr();
+                    let max_atomic_width = bx.sess().max_atomic_width();
+                    if is_atomic_ty && bx.layout_of(ty).size.bits() > max_atomic_width {
+                        bx.tcx().sess.span_err(
+                            span,
+                            &format!(
+                                "`{}` intrinsic on `{}` exceeds the maximum atomic width \
+                                 of this target ({} bits)",
+                                name, ty, max_atomic_width
+                            ),
+                        );
+                        return;
+                    }
+                }
+
                 match split[1] {
                     "cxchg" | "cxchgweak" => {
                         let ty = substs.type_at(0);
@@ -547,6 +566,20 @@ pub fn codegen_intrinsic_call(
                 }
             }
 
+            sym::ptr_addr => {
+                // LLVM has no notion of provenance-exposing casts, so this is the same as
+                // `ptr as usize` for now. Backends for targets with capabilities can lower it
+                // to an address-only read instead.
+                let ptr = args[0].immediate();
+                bx.ptrtoint(ptr, bx.type_isize())
+            }
+
+            sym::ptr_same_allocation => {
+                // Provenance is gone by the time the backend sees the pointers, so
+                // conservatively assume that they could share an allocation.
+                bx.const_bool(true)
+            }
+
             sym::ptr_offset_from => {
                 let ty = substs.type_at(0);
                 let pointee_size = bx.layout_of(ty).size;
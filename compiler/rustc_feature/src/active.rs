@@ -642,6 +642,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     /// Allows `extern "wasm" fn`
     (active, wasm_abi, "1.53.0", Some(83788), None),
 
+    /// Enables `#[cfg(target_simulator)]` config key.
+    (active, cfg_target_simulator, "1.53.0", None, None),
+
     // -------------------------------------------------------------------------
     // feature-group-end: actual feature gates
     // -------------------------------------------------------------------------
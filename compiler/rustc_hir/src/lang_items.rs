@@ -179,6 +179,8 @@ pub fn extract<'a, F>(check_name: F, attrs: &'a [ast::Attribute]) -> Option<(Sym
     MutPtr,                  sym::mut_ptr,             mut_ptr_impl,               Target::Impl;
     ConstSlicePtr,           sym::const_slice_ptr,     const_slice_ptr_impl,       Target::Impl;
     MutSlicePtr,             sym::mut_slice_ptr,       mut_slice_ptr_impl,         Target::Impl;
+    ConstStrPtr,             sym::const_str_ptr,       const_str_ptr_impl,         Target::Impl;
+    MutStrPtr,               sym::mut_str_ptr,         mut_str_ptr_impl,           Target::Impl;
     I8,                      sym::i8,                  i8_impl,                    Target::Impl;
     I16,                     sym::i16,                 i16_impl,                   Target::Impl;
     I32,                     sym::i32,                 i32_impl,                   Target::Impl;
@@ -292,6 +294,10 @@ pub fn extract<'a, F>(check_name: F, attrs: &'a [ast::Attribute]) -> Option<(Sym
 
     Start,                   sym::start,               start_fn,                   Target::Fn;
 
+    /// Selects between a compile-time and a runtime implementation of the same operation.
+    ConstEvalSelect,         sym::const_eval_select,   const_eval_select,          Target::Fn;
+    ConstEvalSelectCt,       sym::const_eval_select_ct, const_eval_select_ct,     Target::Fn;
+
     EhPersonality,           sym::eh_personality,      eh_personality,             Target::Fn;
     EhCatchTypeinfo,         sym::eh_catch_typeinfo,   eh_catch_typeinfo,          Target::Static;
 
@@ -151,9 +151,8 @@ fn register_native_lib(&mut self, span: Option<Span>, lib: NativeLib) {
             }
             return;
         }
-        let is_osx = self.tcx.sess.target.is_like_osx;
-        if lib.kind == NativeLibKind::Framework && !is_osx {
-            let msg = "native frameworks are only available on macOS targets";
+        if lib.kind == NativeLibKind::Framework && !self.tcx.sess.target.supports_frameworks() {
+            let msg = "native frameworks are only available on Apple targets";
             match span {
                 Some(span) => struct_span_err!(self.tcx.sess, span, E0455, "{}", msg).emit(),
                 None => self.tcx.sess.err(msg),
@@ -2,6 +2,7 @@
 use rustc_middle::ty::{self, Ty};
 use std::borrow::Borrow;
 use std::collections::hash_map::Entry;
+use std::convert::TryFrom;
 use std::hash::Hash;
 
 use rustc_data_structures::fx::FxHashMap;
@@ -16,8 +17,8 @@
 use rustc_target::spec::abi::Abi;
 
 use crate::interpret::{
-    self, compile_time_machine, AllocId, Allocation, Frame, ImmTy, InterpCx, InterpResult, Memory,
-    OpTy, PlaceTy, Pointer, Scalar,
+    self, compile_time_machine, AllocCheck, AllocId, Allocation, Frame, ImmTy, InterpCx,
+    InterpResult, Memory, OpTy, PlaceTy, Pointer, PointerArithmetic, Scalar,
 };
 
 use super::error::*;
@@ -48,6 +49,89 @@ fn hook_panic_fn(
             Ok(())
         }
     }
+
+    /// "Intercept" a call to `const_eval_select`, which must run its compile-time
+    /// implementation during CTFE.
+    /// If this returns `Some`, that instance should be called instead.
+    fn hook_const_eval_select(&self, instance: ty::Instance<'tcx>) -> Option<ty::Instance<'tcx>> {
+        if Some(instance.def_id()) != self.tcx.lang_items().const_eval_select() {
+            return None;
+        }
+        // `const_eval_select_ct` has the exact same signature and generics, so the
+        // substitutions carry over unchanged.
+        let ct = self.tcx.lang_items().const_eval_select_ct()?;
+        Some(ty::Instance::new(ct, instance.substs))
+    }
+
+    /// "Intercept" a call to `ptr::align_offset`, whose runtime implementation needs the
+    /// address of the pointer.
+    /// If the allocation is at least as aligned as requested, the answer only depends on the
+    /// offset of the pointer within the allocation, so we can compute it exactly. Otherwise we
+    /// return `usize::MAX`, which callers of `align_offset` must be prepared to handle anyway.
+    /// If this returns `true`, the call has been handled and the result written to `ret`.
+    fn hook_align_offset(
+        &mut self,
+        instance: ty::Instance<'tcx>,
+        args: &[OpTy<'tcx>],
+        ret: Option<(&PlaceTy<'tcx>, mir::BasicBlock)>,
+    ) -> InterpResult<'tcx, bool> {
+        if Some(instance.def_id()) != self.tcx.lang_items().align_offset_fn() {
+            return Ok(false);
+        }
+        let (dest, ret) = match ret {
+            Some(p) => p,
+            // `align_offset` always returns.
+            None => return Ok(false),
+        };
+        let ptr = self.read_scalar(&args[0])?.check_init()?;
+        let align = self.read_scalar(&args[1])?.to_machine_usize(self)?;
+        let stride = self.layout_of(instance.substs.type_at(0))?.size.bytes();
+
+        // The address of `ptr` modulo `align`, if we know it.
+        let addr = match ptr.to_bits_or_ptr(self.pointer_size(), self) {
+            Ok(bits) => Some(u64::try_from(bits).unwrap()),
+            Err(ptr) => {
+                let (_, alloc_align) =
+                    self.memory.get_size_and_align(ptr.alloc_id, AllocCheck::MaybeDead)?;
+                if alloc_align.bytes() >= align { Some(ptr.offset.bytes()) } else { None }
+            }
+        };
+        let offset = addr
+            .and_then(|addr| align_offset(addr, stride, align))
+            .unwrap_or_else(|| self.machine_usize_max());
+        self.write_scalar(Scalar::from_machine_usize(offset, self), dest)?;
+        self.go_to_block(ret);
+        Ok(true)
+    }
+}
+
+/// Computes the smallest number of `stride`-sized elements that `addr` has to be moved by to
+/// become a multiple of `align`, which must be a power of two. Returns `None` if there is none.
+fn align_offset(addr: u64, stride: u64, align: u64) -> Option<u64> {
+    let align_mask = align - 1;
+    // The number of bytes that `addr` falls short of the next multiple of `align`.
+    let missing = addr.wrapping_neg() & align_mask;
+    if missing == 0 {
+        return Some(0);
+    } else if stride == 0 {
+        return None;
+    }
+    // We need to solve `stride * n = missing (mod align)`, which only has a solution if
+    // `gcd(stride, align)` divides `missing`. Dividing everything by the gcd leaves an odd
+    // stride, which has a multiplicative inverse modulo the (still power-of-two) alignment.
+    let gcd_pow = stride.trailing_zeros().min(align.trailing_zeros());
+    if missing & ((1 << gcd_pow) - 1) != 0 {
+        return None;
+    }
+    let (stride, missing, align_mask) =
+        (stride >> gcd_pow, missing >> gcd_pow, align_mask >> gcd_pow);
+    // Every odd number is its own inverse modulo 8, and each Newton step
+    // `y = y * (2 - x * y)` doubles the number of correct low bits: 3, 6, 12, 24, 48, 96.
+    let mut inverse = stride;
+    for _ in 0..5 {
+        inverse = inverse.wrapping_mul(2u64.wrapping_sub(stride.wrapping_mul(inverse)));
+    }
+    Some(missing.wrapping_mul(inverse) & align_mask)
 }
 
 /// Extra machine state for CTFE, and the Machine instance
@@ -227,6 +311,15 @@ fn find_mir_or_eval_fn(
     ) -> InterpResult<'tcx, Option<&'mir mir::Body<'tcx>>> {
         debug!("find_mir_or_eval_fn: {:?}", instance);
 
+        if let Some(new_instance) = ecx.hook_const_eval_select(instance) {
+            // We call the compile-time implementation instead.
+            return Self::find_mir_or_eval_fn(ecx, new_instance, _abi, args, _ret, _unwind);
+        }
+
+        if ecx.hook_align_offset(instance, args, _ret)? {
+            return Ok(None);
+        }
+
         // Only check non-glue functions
         if let ty::InstanceDef::Item(def) = instance.def {
             // Execution might have wandered off into other crates, so we cannot do a stability-
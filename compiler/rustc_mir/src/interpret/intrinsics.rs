@@ -7,7 +7,7 @@
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
     self,
-    interpret::{ConstValue, GlobalId, InterpResult, Scalar},
+    interpret::{ConstValue, GlobalId, InterpResult, PointerArithmetic, Scalar},
     BinOp,
 };
 use rustc_middle::ty;
@@ -325,6 +325,9 @@ pub fn emulate_intrinsic(
             sym::copy => {
                 self.copy(&args[0], &args[1], &args[2], /*nonoverlapping*/ false)?;
             }
+            sym::write_bytes => {
+                self.write_bytes_intrinsic(&args[0], &args[1], &args[2])?;
+            }
             sym::offset => {
                 let ptr = self.read_scalar(&args[0])?.check_init()?;
                 let offset_count = self.read_scalar(&args[1])?.to_machine_isize(self)?;
@@ -391,6 +394,24 @@ pub fn emulate_intrinsic(
                     self.exact_div(&val, &size, dest)?;
                 }
             }
+            sym::ptr_addr => {
+                // Querying the address must not expose the provenance of the pointer, so we
+                // do not go through the cast machinery. Whether an address can be produced at
+                // all is up to the machine: CTFE cannot, since allocations are not placed yet.
+                let ptr = self.read_scalar(&args[0])?.check_init()?;
+                let addr = u64::try_from(self.force_bits(ptr, self.pointer_size())?).unwrap();
+                self.write_scalar(Scalar::from_machine_usize(addr, self), dest)?;
+            }
+            sym::ptr_same_allocation => {
+                let a = self.read_scalar(&args[0])?.check_init()?;
+                let b = self.read_scalar(&args[1])?.check_init()?;
+                let same = match (a, b) {
+                    (Scalar::Ptr(a), Scalar::Ptr(b)) => a.alloc_id == b.alloc_id,
+                    // Integer addresses carry no provenance, so nothing can be proven.
+                    _ => true,
+                };
+                self.write_scalar(Scalar::from_bool(same), dest)?;
+            }
 
             sym::transmute => {
                 self.copy_op_transmute(&args[0], dest)?;
@@ -475,6 +496,29 @@ pub fn emulate_intrinsic(
         Ok(true)
     }
 
+    pub(crate) fn write_bytes_intrinsic(
+        &mut self,
+        dst: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::PointerTag>,
+        byte: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::PointerTag>,
+        count: &OpTy<'tcx, <M as Machine<'mir, 'tcx>>::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let layout = self.layout_of(dst.layout.ty.builtin_deref(true).unwrap().ty)?;
+        let byte = self.read_scalar(&byte)?.to_u8()?;
+        let count = self.read_scalar(&count)?.to_machine_usize(self)?;
+        let len = layout
+            .size
+            .checked_mul(count, self)
+            .ok_or_else(|| err_ub_format!("overflow computing total size of `write_bytes`"))?;
+
+        // Like `copy`, check the pointer for an access of the total size and alignment,
+        // *even if* the total size is 0.
+        let dst = self.read_scalar(&dst)?.check_init()?;
+        self.memory.check_ptr_access(dst, len, layout.align.abi)?;
+
+        let bytes = std::iter::repeat(byte).take(len.bytes_usize());
+        self.memory.write_bytes(dst, bytes)
+    }
+
     pub fn exact_div(
         &mut self,
         a: &ImmTy<'tcx, M::PointerTag>,
@@ -510,9 +554,28 @@ pub fn ptr_offset_inbounds(
     ) -> InterpResult<'tcx, Scalar<M::PointerTag>> {
         // We cannot overflow i64 as a type's size must be <= isize::MAX.
         let pointee_size = i64::try_from(self.layout_of(pointee_ty)?.size.bytes()).unwrap();
-        // The computed offset, in bytes, cannot overflow an isize.
-        let offset_bytes =
-            offset_count.checked_mul(pointee_size).ok_or(err_ub!(PointerArithOverflow))?;
+        // The computed offset, in bytes, cannot overflow an isize. Compute it in `i128` so that
+        // the error can report the full byte count.
+        let offset_bytes = i128::from(offset_count) * i128::from(pointee_size);
+        if offset_bytes > i128::from(self.machine_isize_max()) {
+            throw_ub_format!(
+                "overflow computing offset: {} bytes × {} elements = {} bytes, \
+                 which exceeds isize::MAX",
+                pointee_size,
+                offset_count,
+                offset_bytes,
+            );
+        }
+        if offset_bytes < i128::from(self.machine_isize_min()) {
+            throw_ub_format!(
+                "overflow computing offset: {} bytes × {} elements = {} bytes, \
+                 which is below isize::MIN",
+                pointee_size,
+                offset_count,
+                offset_bytes,
+            );
+        }
+        let offset_bytes = i64::try_from(offset_bytes).unwrap();
         // The offset being in bounds cannot rely on "wrapping around" the address space.
         // So, first rule out overflows in the pointer arithmetic.
         let offset_ptr = ptr.ptr_signed_offset(offset_bytes, self)?;
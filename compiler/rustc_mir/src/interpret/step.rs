@@ -167,7 +167,10 @@ pub(crate) fn copy(
         })?;
 
         // Make sure we check both pointers for an access of the total size and aligment,
-        // *even if* the total size is 0.
+        // *even if* the total size is 0. For a zero-count or ZST copy, an integer pointer only
+        // needs to be non-null and aligned, so pointers such as `NonNull::dangling()` are
+        // accepted and nothing gets copied. A pointer into an allocation must still point into
+        // (or one past the end of) a live one, just as for any other zero-sized access.
         let src =
             self.memory.check_ptr_access(self.read_scalar(&src)?.check_init()?, size, align)?;
 
@@ -220,6 +220,12 @@ pub fn check_body(&mut self) {
         let ConstCx { tcx, body, .. } = *self.ccx;
         let def_id = self.ccx.def_id();
 
+        // The body of a `#[rustc_do_not_const_check]` function is never evaluated by CTFE; the
+        // interpreter swaps it out for a const-checked counterpart instead.
+        if tcx.has_attr(def_id.to_def_id(), sym::rustc_do_not_const_check) {
+            return;
+        }
+
         // `async` functions cannot be `const fn`. This is checked during AST lowering, so there's
         // no need to emit duplicate errors here.
         if is_async_fn(self.ccx) || body.generator.is_some() {
@@ -805,7 +805,7 @@ pub fn default_configuration(sess: &Session) -> CrateConfig {
     let env = &sess.target.env;
     let vendor = &sess.target.vendor;
     let min_atomic_width = sess.target.min_atomic_width();
-    let max_atomic_width = sess.target.max_atomic_width();
+    let max_atomic_width = sess.max_atomic_width();
     let atomic_cas = sess.target.atomic_cas;
     let layout = TargetDataLayout::parse(&sess.target).unwrap_or_else(|err| {
         sess.fatal(&err);
@@ -831,6 +831,9 @@ pub fn default_configuration(sess: &Session) -> CrateConfig {
     if sess.target.has_elf_tls {
         ret.insert((sym::target_thread_local, None));
     }
+    if sess.target.simulator {
+        ret.insert((sym::target_simulator, None));
+    }
     for &(i, align) in &[
         (8, layout.i8_align.abi),
         (16, layout.i16_align.abi),
@@ -2290,7 +2293,9 @@ pub fn needs_analysis(&self) -> bool {
     use rustc_feature::UnstableFeatures;
     use rustc_span::edition::Edition;
     use rustc_target::spec::{CodeModel, MergeFunctions, PanicStrategy, RelocModel};
-    use rustc_target::spec::{RelroLevel, SanitizerSet, SplitDebuginfo, TargetTriple, TlsModel};
+    use rustc_target::spec::{
+        FramePointer, RelroLevel, SanitizerSet, SplitDebuginfo, TargetTriple, TlsModel,
+    };
     use std::collections::hash_map::DefaultHasher;
     use std::collections::BTreeMap;
     use std::hash::Hash;
@@ -2367,6 +2372,7 @@ fn hash(&self, hasher: &mut DefaultHasher, error_format: ErrorOutputType) {
     impl_dep_tracking_hash_via_hash!(Edition);
     impl_dep_tracking_hash_via_hash!(LinkerPluginLto);
     impl_dep_tracking_hash_via_hash!(Option<SplitDebuginfo>);
+    impl_dep_tracking_hash_via_hash!(Option<FramePointer>);
     impl_dep_tracking_hash_via_hash!(SwitchWithOptPath);
     impl_dep_tracking_hash_via_hash!(Option<SymbolManglingVersion>);
     impl_dep_tracking_hash_via_hash!(Option<SourceFileHashAlgorithm>);
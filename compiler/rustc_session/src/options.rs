@@ -6,7 +6,9 @@
 use crate::utils::NativeLibKind;
 
 use rustc_target::spec::{CodeModel, LinkerFlavor, MergeFunctions, PanicStrategy, SanitizerSet};
-use rustc_target::spec::{RelocModel, RelroLevel, SplitDebuginfo, TargetTriple, TlsModel};
+use rustc_target::spec::{
+    FramePointer, RelocModel, RelroLevel, SplitDebuginfo, TargetTriple, TlsModel,
+};
 
 use rustc_feature::UnstableFeatures;
 use rustc_span::edition::Edition;
@@ -287,6 +289,8 @@ mod $mod_desc {
         pub const parse_wasi_exec_model: &str = "either `command` or `reactor`";
         pub const parse_split_debuginfo: &str =
             "one of supported split-debuginfo modes (`off` or `dsymutil`)";
+        pub const parse_frame_pointer: &str =
+            "one of: `y`, `yes`, `on`, `n`, `no`, `off`, `non-leaf`, `leaf`, or `unwind`";
     }
 
     #[allow(dead_code)]
@@ -770,6 +774,18 @@ fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) ->
             }
             true
         }
+
+        fn parse_frame_pointer(slot: &mut Option<FramePointer>, v: Option<&str>) -> bool {
+            match v {
+                Some("y") | Some("yes") | Some("on") | None => *slot = Some(FramePointer::Always),
+                Some("n") | Some("no") | Some("off") => *slot = Some(FramePointer::MayOmit),
+                Some("non-leaf") => *slot = Some(FramePointer::NonLeaf),
+                Some("leaf") => *slot = Some(FramePointer::Leaf),
+                Some("unwind") => *slot = Some(FramePointer::Unwind),
+                _ => return false,
+            }
+            true
+        }
     }
 ) }
 
@@ -802,8 +818,8 @@ fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) ->
         "emit bitcode in rlibs (default: yes)"),
     extra_filename: String = (String::new(), parse_string, [UNTRACKED],
         "extra data to put in each output filename"),
-    force_frame_pointers: Option<bool> = (None, parse_opt_bool, [TRACKED],
-        "force use of the frame pointers"),
+    force_frame_pointers: Option<FramePointer> = (None, parse_frame_pointer, [TRACKED],
+        "force use of the frame pointers (`yes`, `no`, `non-leaf`, `leaf`, or `unwind`)"),
     force_unwind_tables: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "force use of unwind tables"),
     incremental: Option<String> = (None, parse_opt_string, [UNTRACKED],
@@ -1026,6 +1042,9 @@ fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) ->
         "list the symbols defined by a library crate (default: no)"),
     macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
         "show macro backtraces (default: no)"),
+    max_atomic_width: Option<u64> = (None, parse_opt_number, [TRACKED],
+        "lower the maximum size in bits of atomic operations below the target's default \
+        (larger values are ignored)"),
     merge_functions: Option<MergeFunctions> = (None, parse_merge_functions, [TRACKED],
         "control the operation of the MergeFunctions LLVM pass, taking \
         the same values as the target option of the same name"),
@@ -1088,6 +1107,9 @@ fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) ->
         See #77382 and #74551."),
     print_fuel: Option<String> = (None, parse_opt_string, [TRACKED],
         "make rustc print the total optimization fuel used by a crate"),
+    print_frame_pointer_decisions: bool = (false, parse_bool, [UNTRACKED],
+        "print the frame pointer attribute of each function and which setting decided it \
+        (default: no)"),
     print_link_args: bool = (false, parse_bool, [UNTRACKED],
         "print the arguments passed to the linker (default: no)"),
     print_llvm_passes: bool = (false, parse_bool, [UNTRACKED],
@@ -27,7 +27,7 @@
 use rustc_span::source_map::{FileLoader, MultiSpan, RealFileLoader, SourceMap, Span};
 use rustc_span::{sym, SourceFileHashAlgorithm, Symbol};
 use rustc_target::asm::InlineAsmArch;
-use rustc_target::spec::{CodeModel, PanicStrategy, RelocModel, RelroLevel};
+use rustc_target::spec::{CodeModel, FramePointer, PanicStrategy, RelocModel, RelroLevel};
 use rustc_target::spec::{SanitizerSet, SplitDebuginfo, Target, TargetTriple, TlsModel};
 
 use std::cell::{self, RefCell};
@@ -64,6 +64,33 @@ pub enum CtfeBacktrace {
     Immediate,
 }
 
+/// Which setting decided the frame pointer policy returned by [`Session::frame_pointer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FramePointerSource {
+    /// `-Z instrument-mcount`, whose `mcount` calls rely on frame pointers.
+    Mcount,
+    /// An explicit `-C force-frame-pointers`.
+    Flag,
+    /// The target's policy, which it requires even though `-C force-frame-pointers` was given.
+    MandatoryTarget,
+    /// The target's policy for code built with a sanitizer.
+    Sanitizer,
+    /// The target's default policy.
+    Target,
+}
+
+impl fmt::Display for FramePointerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FramePointerSource::Mcount => "-Z instrument-mcount",
+            FramePointerSource::Flag => "-C force-frame-pointers",
+            FramePointerSource::MandatoryTarget => "mandatory target policy",
+            FramePointerSource::Sanitizer => "target sanitizer policy",
+            FramePointerSource::Target => "target default",
+        })
+    }
+}
+
 /// New-type wrapper around `usize` for representing limits. Ensures that comparisons against
 /// limits are consistent throughout the compiler.
 #[derive(Clone, Copy, Debug)]
@@ -712,6 +739,15 @@ pub fn lto(&self) -> config::Lto {
     pub fn panic_strategy(&self) -> PanicStrategy {
         self.opts.cg.panic.unwrap_or(self.target.panic_strategy)
     }
+    /// Returns the maximum integer size in bits that atomic operations can be performed on.
+    /// '-Z max-atomic-width' can lower the limit defined by the target, but never raise it.
+    pub fn max_atomic_width(&self) -> u64 {
+        let target_width = self.target.max_atomic_width();
+        match self.opts.debugging_opts.max_atomic_width {
+            Some(width) => width.min(target_width),
+            None => target_width,
+        }
+    }
     pub fn fewer_names(&self) -> bool {
         if let Some(fewer_names) = self.opts.debugging_opts.fewer_names {
             fewer_names
@@ -791,15 +827,29 @@ pub fn target_can_use_split_dwarf(&self) -> bool {
         !self.target.is_like_windows && !self.target.is_like_osx
     }
 
-    pub fn must_not_eliminate_frame_pointers(&self) -> bool {
+    pub fn frame_pointer(&self) -> FramePointer {
+        self.frame_pointer_with_source().0
+    }
+
+    /// Like [`Session::frame_pointer`], but also returns which setting decided the policy.
+    pub fn frame_pointer_with_source(&self) -> (FramePointer, FramePointerSource) {
         // "mcount" function relies on stack pointer.
         // See <https://sourceware.org/binutils/docs/gprof/Implementation.html>.
         if self.instrument_mcount() {
-            true
+            (FramePointer::Always, FramePointerSource::Mcount)
         } else if let Some(x) = self.opts.cg.force_frame_pointers {
-            x
+            // Targets that need their frame pointers keep them, unless asked for all of them.
+            // See `validate_commandline_args_with_session_available` for the warning.
+            if self.target.mandatory_frame_pointers && x != FramePointer::Always {
+                (self.target.frame_pointer, FramePointerSource::MandatoryTarget)
+            } else {
+                (x, FramePointerSource::Flag)
+            }
+        } else if !self.opts.debugging_opts.sanitizer.is_empty() {
+            // The sanitizer runtimes unwind the stack with frame pointers to report errors.
+            (self.target.sanitizer_frame_pointer, FramePointerSource::Sanitizer)
         } else {
-            !self.target.eliminate_frame_pointer
+            (self.target.frame_pointer, FramePointerSource::Target)
         }
     }
 
@@ -1481,6 +1531,19 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         }
     }
 
+    // Frameworks only exist on Apple targets, so searching for them anywhere else is a mistake.
+    if !sess.target.supports_frameworks() {
+        for search_path in &sess.opts.search_paths {
+            if search_path.kind == PathKind::Framework {
+                sess.err(&format!(
+                    "framework search paths are only available on Apple targets \
+                     (found `-L framework={}`)",
+                    search_path.dir.display()
+                ));
+            }
+        }
+    }
+
     // Unwind tables cannot be disabled if the target requires them.
     if let Some(include_uwtables) = sess.opts.cg.force_unwind_tables {
         if sess.target.requires_uwtable && !include_uwtables {
@@ -1491,6 +1554,19 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         }
     }
 
+    // Frame pointers cannot be weakened if the target requires them.
+    if let Some(frame_pointer) = sess.opts.cg.force_frame_pointers {
+        if sess.target.mandatory_frame_pointers
+            && frame_pointer != FramePointer::Always
+            && frame_pointer != sess.target.frame_pointer
+        {
+            sess.warn(&format!(
+                "target requires `{}` frame pointers, ignoring `-C force-frame-pointers`",
+                sess.target.frame_pointer,
+            ));
+        }
+    }
+
     // PGO does not work reliably with panic=unwind on Windows. Let's make it
     // an error to combine the two for now. It always runs into an assertions
     // if LLVM is built with assertions, but without assertions it sometimes
@@ -350,6 +350,7 @@
         cfg_sanitize,
         cfg_target_feature,
         cfg_target_has_atomic,
+        cfg_target_simulator,
         cfg_target_thread_local,
         cfg_target_vendor,
         cfg_version,
@@ -377,6 +378,8 @@
         const_compare_raw_pointers,
         const_constructor,
         const_eval_limit,
+        const_eval_select,
+        const_eval_select_ct,
         const_evaluatable_checked,
         const_extern_fn,
         const_fn,
@@ -401,6 +404,7 @@
         const_raw_ptr_to_usize_cast,
         const_refs_to_cell,
         const_slice_ptr,
+        const_str_ptr,
         const_trait_bound_opt_out,
         const_trait_impl,
         const_transmute,
@@ -755,6 +759,7 @@
         must_use,
         mut_ptr,
         mut_slice_ptr,
+        mut_str_ptr,
         naked,
         naked_functions,
         name,
@@ -898,11 +903,13 @@
         proc_macro_path_invoc,
         profiler_builtins,
         profiler_runtime,
+        ptr_addr,
         ptr_guaranteed_eq,
         ptr_guaranteed_ne,
         ptr_null,
         ptr_null_mut,
         ptr_offset_from,
+        ptr_same_allocation,
         pub_macro_rules,
         pub_restricted,
         pure,
@@ -995,6 +1002,7 @@
         rustc_diagnostic_item,
         rustc_diagnostic_macros,
         rustc_dirty,
+        rustc_do_not_const_check,
         rustc_dummy,
         rustc_dump_env_program_clauses,
         rustc_dump_program_clauses,
@@ -1184,6 +1192,7 @@
         target_has_atomic_load_store,
         target_os,
         target_pointer_width,
+        target_simulator,
         target_target_vendor,
         target_thread_local,
         target_vendor,
@@ -1,4 +1,4 @@
-use crate::spec::TargetOptions;
+use crate::spec::{FramePointer, TargetOptions};
 
 use Arch::*;
 #[allow(non_camel_case_types)]
@@ -44,7 +44,8 @@ pub fn opts(os: &str, arch: Arch) -> TargetOptions {
         executables: true,
         link_env_remove: link_env_remove(arch),
         has_elf_tls: false,
-        eliminate_frame_pointer: false,
+        frame_pointer: FramePointer::Always,
+        simulator: matches!(arch, I386 | X86_64 | Arm64_sim),
         ..super::apple_base::opts(os)
     }
 }
@@ -1,4 +1,6 @@
-use crate::spec::{PanicStrategy, RelocModel, RelroLevel, StackProbeType, TargetOptions};
+use crate::spec::{
+    FramePointer, PanicStrategy, RelocModel, RelroLevel, StackProbeType, TargetOptions,
+};
 
 pub fn opts() -> TargetOptions {
     TargetOptions {
@@ -6,7 +8,7 @@ pub fn opts() -> TargetOptions {
         disable_redzone: true,
         panic_strategy: PanicStrategy::Abort,
         stack_probes: StackProbeType::InlineOrCall { min_llvm_version_for_inline: (11, 0, 1) },
-        eliminate_frame_pointer: false,
+        frame_pointer: FramePointer::Always,
         linker_is_gnu: true,
         position_independent_executables: true,
         needs_plt: true,
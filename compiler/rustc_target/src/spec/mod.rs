@@ -512,6 +512,63 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Whether generated code keeps frame pointers.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq)]
+pub enum FramePointer {
+    /// Frame pointers may be omitted wherever the backend sees fit.
+    MayOmit,
+    /// Frame pointers are kept in every function that may unwind, including leaf functions,
+    /// but may be omitted in functions that cannot unwind.
+    Unwind,
+    /// Frame pointers are kept in functions that call other functions, but may be omitted in
+    /// leaf functions.
+    NonLeaf,
+    /// Frame pointers are kept in leaf functions, but may be omitted in functions that call
+    /// other functions.
+    Leaf,
+    /// Every function keeps its frame pointer.
+    Always,
+}
+
+impl FramePointer {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FramePointer::MayOmit => "may-omit",
+            FramePointer::Unwind => "unwind",
+            FramePointer::NonLeaf => "non-leaf",
+            FramePointer::Leaf => "leaf",
+            FramePointer::Always => "always",
+        }
+    }
+}
+
+impl FromStr for FramePointer {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<FramePointer, ()> {
+        Ok(match s {
+            "may-omit" => FramePointer::MayOmit,
+            "unwind" => FramePointer::Unwind,
+            "non-leaf" => FramePointer::NonLeaf,
+            "leaf" => FramePointer::Leaf,
+            "always" => FramePointer::Always,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl ToJson for FramePointer {
+    fn to_json(&self) -> Json {
+        self.as_str().to_json()
+    }
+}
+
+impl fmt::Display for FramePointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum StackProbeType {
     /// Don't emit any stack probes.
@@ -1028,8 +1085,16 @@ pub struct TargetOptions {
     pub tls_model: TlsModel,
     /// Do not emit code that uses the "red zone", if the ABI has one. Defaults to false.
     pub disable_redzone: bool,
-    /// Eliminate frame pointers from stack frames if possible. Defaults to true.
-    pub eliminate_frame_pointer: bool,
+    /// Whether frame pointers are kept in stack frames, unless overridden by
+    /// `-C force-frame-pointers`. Defaults to `MayOmit`.
+    pub frame_pointer: FramePointer,
+    /// Whether `frame_pointer` is required for correctness, for example by the platform ABI,
+    /// so that `-C force-frame-pointers` may only ask for more frame pointers. Defaults to false.
+    pub mandatory_frame_pointers: bool,
+    /// Frame pointer policy used instead of `frame_pointer` when a sanitizer is enabled, since
+    /// the sanitizer runtimes collect their stack traces by walking frame pointers. Still
+    /// overridden by `-C force-frame-pointers`. Defaults to `Always`.
+    pub sanitizer_frame_pointer: FramePointer,
     /// Emit each function in its own section. Defaults to true.
     pub function_sections: bool,
     /// String to prepend to the name of every dynamic library. Defaults to "lib".
@@ -1079,6 +1144,9 @@ pub struct TargetOptions {
     pub is_like_fuchsia: bool,
     /// Whether a target toolchain is like WASM.
     pub is_like_wasm: bool,
+    /// Whether the target runs in a simulator of another platform, such as the iOS simulator.
+    /// Exposed to user code as `cfg(target_simulator)`. Defaults to false.
+    pub simulator: bool,
     /// Version of DWARF to use if not using the default.
     /// Useful because some platforms (osx, bsd) only want up to DWARF2.
     pub dwarf_version: Option<u32>,
@@ -1286,7 +1354,9 @@ fn default() -> TargetOptions {
             code_model: None,
             tls_model: TlsModel::GeneralDynamic,
             disable_redzone: false,
-            eliminate_frame_pointer: true,
+            frame_pointer: FramePointer::MayOmit,
+            mandatory_frame_pointers: false,
+            sanitizer_frame_pointer: FramePointer::Always,
             function_sections: true,
             dll_prefix: "lib".to_string(),
             dll_suffix: ".so".to_string(),
@@ -1302,6 +1372,7 @@ fn default() -> TargetOptions {
             is_like_msvc: false,
             is_like_fuchsia: false,
             is_like_wasm: false,
+            simulator: false,
             dwarf_version: None,
             linker_is_gnu: false,
             allows_weak_linkage: true,
@@ -1431,6 +1502,11 @@ pub fn max_atomic_width(&self) -> u64 {
         self.max_atomic_width.unwrap_or_else(|| self.pointer_width.into())
     }
 
+    /// Whether native frameworks can be linked to and searched for on this target.
+    pub fn supports_frameworks(&self) -> bool {
+        self.is_like_osx
+    }
+
     pub fn is_abi_supported(&self, abi: Abi) -> bool {
         abi.generic() || !self.unsupported_abis.contains(&abi)
     }
@@ -1581,6 +1657,18 @@ macro_rules! key {
                     Some(Ok(()))
                 })).unwrap_or(Ok(()))
             } );
+            ($key_name:ident, FramePointer) => ( {
+                let name = (stringify!($key_name)).replace("_", "-");
+                obj.find(&name[..]).and_then(|o| o.as_string().and_then(|s| {
+                    match s.parse::<FramePointer>() {
+                        Ok(policy) => base.$key_name = policy,
+                        _ => return Some(Err(format!("'{}' is not a valid value for \
+                                                      {}. Use 'always', 'non-leaf', 'leaf', \
+                                                      'unwind' or 'may-omit'.", s, name))),
+                    }
+                    Some(Ok(()))
+                })).unwrap_or(Ok(()))
+            } );
             ($key_name:ident, list) => ( {
                 let name = (stringify!($key_name)).replace("_", "-");
                 if let Some(v) = obj.find(&name).and_then(Json::as_array) {
@@ -1795,7 +1883,13 @@ macro_rules! key {
         key!(code_model, CodeModel)?;
         key!(tls_model, TlsModel)?;
         key!(disable_redzone, bool);
-        key!(eliminate_frame_pointer, bool);
+        key!(frame_pointer, FramePointer)?;
+        key!(mandatory_frame_pointers, bool);
+        key!(sanitizer_frame_pointer, FramePointer)?;
+        // Older target specs used a boolean for this.
+        if let Some(false) = obj.find("eliminate-frame-pointer").and_then(Json::as_boolean) {
+            base.frame_pointer = FramePointer::Always;
+        }
         key!(function_sections, bool);
         key!(dll_prefix);
         key!(dll_suffix);
@@ -1811,6 +1905,7 @@ macro_rules! key {
         key!(is_like_emscripten, bool);
         key!(is_like_fuchsia, bool);
         key!(is_like_wasm, bool);
+        key!(simulator, bool);
         key!(dwarf_version, Option<u32>);
         key!(linker_is_gnu, bool);
         key!(allows_weak_linkage, bool);
@@ -2035,7 +2130,9 @@ macro_rules! target_option_val {
         target_option_val!(code_model);
         target_option_val!(tls_model);
         target_option_val!(disable_redzone);
-        target_option_val!(eliminate_frame_pointer);
+        target_option_val!(frame_pointer);
+        target_option_val!(mandatory_frame_pointers);
+        target_option_val!(sanitizer_frame_pointer);
         target_option_val!(function_sections);
         target_option_val!(dll_prefix);
         target_option_val!(dll_suffix);
@@ -2051,6 +2148,7 @@ macro_rules! target_option_val {
         target_option_val!(is_like_emscripten);
         target_option_val!(is_like_fuchsia);
         target_option_val!(is_like_wasm);
+        target_option_val!(simulator);
         target_option_val!(dwarf_version);
         target_option_val!(linker_is_gnu);
         target_option_val!(allows_weak_linkage);
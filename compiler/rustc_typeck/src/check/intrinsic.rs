@@ -82,6 +82,8 @@ pub fn intrinsic_operation_unsafety(intrinsic: Symbol) -> hir::Unsafety {
         | sym::unlikely
         | sym::ptr_guaranteed_eq
         | sym::ptr_guaranteed_ne
+        | sym::ptr_addr
+        | sym::ptr_same_allocation
         | sym::minnumf32
         | sym::minnumf64
         | sym::maxnumf32
@@ -287,6 +289,10 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
             sym::ptr_offset_from => {
                 (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.isize)
             }
+            sym::ptr_addr => (1, vec![tcx.mk_imm_ptr(param(0))], tcx.types.usize),
+            sym::ptr_same_allocation => {
+                (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.bool)
+            }
             sym::unchecked_div | sym::unchecked_rem | sym::exact_div => {
                 (1, vec![param(0), param(0)], param(0))
             }
@@ -154,6 +154,32 @@ fn visit_item(&mut self, item: &hir::Item<'_>) {
                     assoc_items,
                 );
             }
+            ty::RawPtr(ty::TypeAndMut { ty: inner, mutbl: hir::Mutability::Not })
+                if matches!(inner.kind(), ty::Str) =>
+            {
+                self.check_primitive_impl(
+                    item.def_id,
+                    lang_items.const_str_ptr_impl(),
+                    None,
+                    "const_str_ptr",
+                    "*const str",
+                    item.span,
+                    assoc_items,
+                );
+            }
+            ty::RawPtr(ty::TypeAndMut { ty: inner, mutbl: hir::Mutability::Mut })
+                if matches!(inner.kind(), ty::Str) =>
+            {
+                self.check_primitive_impl(
+                    item.def_id,
+                    lang_items.mut_str_ptr_impl(),
+                    None,
+                    "mut_str_ptr",
+                    "*mut str",
+                    item.span,
+                    assoc_items,
+                );
+            }
             ty::RawPtr(ty::TypeAndMut { ty: _, mutbl: hir::Mutability::Not }) => {
                 self.check_primitive_impl(
                     item.def_id,
@@ -72,6 +72,7 @@
 #![feature(const_assert_type)]
 #![feature(const_discriminant)]
 #![feature(const_cell_into_inner)]
+#![feature(const_eval_select)]
 #![feature(const_intrinsic_copy)]
 #![feature(const_intrinsic_forget)]
 #![feature(const_float_classify)]
@@ -88,16 +89,20 @@
 #![feature(const_fn_fn_ptr_basics)]
 #![feature(const_option)]
 #![feature(const_precise_live_drops)]
+#![feature(const_ptr_is_null)]
 #![feature(const_ptr_offset)]
 #![feature(const_ptr_offset_from)]
 #![feature(const_ptr_read)]
 #![feature(const_ptr_write)]
+#![feature(const_ptr_volatile)]
 #![feature(const_raw_ptr_comparison)]
 #![feature(const_raw_ptr_deref)]
 #![feature(const_slice_from_raw_parts)]
 #![feature(const_slice_ptr_len)]
+#![feature(const_slice_ptr_align_to)]
 #![feature(const_size_of_val)]
 #![feature(const_swap)]
+#![feature(const_align_offset)]
 #![feature(const_align_of_val)]
 #![feature(const_type_id)]
 #![feature(const_type_name)]
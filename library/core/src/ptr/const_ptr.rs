@@ -22,6 +22,10 @@ impl<T: ?Sized> *const T {
     /// the absolute position of that memory, so we cannot tell if the pointer is
     /// null or not.
     ///
+    /// The answer is exact for pointers created from integers, such as
+    /// [`null`](crate::ptr::null), and for pointers that are in bounds of the memory they
+    /// point to, such as references to `static` or `const` items.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -48,6 +52,161 @@ pub const fn cast<U>(self) -> *const U {
         self as _
     }
 
+    /// Casts to a pointer of another type, asserting in debug builds that the pointer is
+    /// aligned for the new type.
+    ///
+    /// This behaves like [`cast`](#method.cast), but catches the common mistake of casting a
+    /// byte pointer to a pointer to a larger type at an address that is not suitably aligned
+    /// for it. Pointers to zero-sized types are not checked, and nothing is checked during
+    /// const evaluation, where misaligned accesses are rejected on their own.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be aligned to `align_of::<U>()`. Violating this is only detected in builds
+    /// of the standard library with debug assertions enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_cast_aligned)]
+    ///
+    /// let words = [1u32, 2];
+    /// let bytes = words.as_ptr().cast::<u8>();
+    /// let second = unsafe { bytes.add(4).cast_aligned::<u32>() };
+    /// assert_eq!(unsafe { second.read() }, 2);
+    /// ```
+    #[unstable(feature = "ptr_cast_aligned", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_cast_aligned", issue = "none")]
+    #[inline]
+    pub const unsafe fn cast_aligned<U>(self) -> *const U {
+        let ptr = self.cast::<U>();
+        debug_assert_aligned(ptr);
+        ptr
+    }
+
+    /// Gets the address portion of the pointer.
+    ///
+    /// Unlike `self as usize`, this does not *expose* the provenance of the pointer: the
+    /// returned integer only describes where the pointer points, and turning it back into
+    /// a pointer does not give back permission to access the memory it pointed to. Use
+    /// [`expose_addr`](#method.expose_addr) when the integer must be convertible back into
+    /// a usable pointer.
+    ///
+    /// For fat pointers, only the data address is returned and the metadata is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = 5u32;
+    /// let ptr = &mut x as *const u32;
+    /// assert_eq!(ptr.addr(), ptr.expose_addr());
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn addr(self) -> usize {
+        // Go through `()` so that fat pointers only contribute their data address.
+        intrinsics::ptr_addr(self.cast::<()>())
+    }
+
+    /// Gets the address portion of the pointer and exposes its provenance.
+    ///
+    /// This is equivalent to `self as usize`: integers obtained this way may later be
+    /// cast back into a pointer that is allowed to access the same memory. Prefer
+    /// [`addr`](#method.addr) whenever only the address itself is needed, as exposing
+    /// provenance hinders alias analysis and is not supported on every target.
+    ///
+    /// For fat pointers, only the data address is returned and the metadata is discarded.
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn expose_addr(self) -> usize {
+        self.cast::<()>() as usize
+    }
+
+    /// Creates a new pointer with the given address and the provenance (and, for fat
+    /// pointers, the metadata) of `self`.
+    ///
+    /// This is the strict-provenance way of changing the address of a pointer: unlike
+    /// casting an integer into a pointer, the result may still be used to access the memory
+    /// `self` is allowed to access, as long as the new address is in bounds of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let ptr = data.as_ptr() as *const u16;
+    /// let last = ptr.with_addr(ptr.addr() + 2 * std::mem::size_of::<u16>());
+    /// assert_eq!(unsafe { *last }, 3);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self {
+        // Offset `self` instead of casting `addr`, so that the provenance is kept. The offset
+        // may wrap around, since the new address is not required to be in bounds, so this
+        // bypasses the `debug_ptr_wrap` check in `wrapping_offset`.
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        // SAFETY: the `arith_offset` intrinsic has no prerequisites to be called.
+        let ptr = unsafe { intrinsics::arith_offset(self.cast::<u8>(), offset) };
+        self.set_ptr_value(ptr)
+    }
+
+    /// Creates a new pointer by mapping the address of `self` to a new one, keeping the
+    /// provenance of `self`.
+    ///
+    /// This is a convenience for [`with_addr`](#method.with_addr), mostly useful for
+    /// tagging and untagging the low bits of aligned pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let x = 7u32;
+    /// let ptr = &x as *const u32 as *const u32;
+    /// let tagged = ptr.map_addr(|addr| addr | 1);
+    /// let untagged = tagged.map_addr(|addr| addr & !1);
+    /// assert_eq!(unsafe { *untagged }, 7);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self {
+        self.with_addr(f(self.addr()))
+    }
+
+    /// Forms a (possibly wide) raw pointer from a data address and metadata.
+    ///
+    /// This is the inverse of [`to_raw_parts`](#method.to_raw_parts), and the same as the
+    /// [`from_raw_parts`] free function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_metadata)]
+    /// use std::fmt::Debug;
+    ///
+    /// let mut data = [1u8, 2, 3];
+    /// let slice = &mut data[..] as *const [u8];
+    /// let (address, len) = slice.to_raw_parts();
+    /// assert_eq!(<*const [u8]>::from_raw_parts(address, len), slice);
+    ///
+    /// let mut value = 5u32;
+    /// let debug = &mut value as *const dyn Debug;
+    /// let (address, vtable) = debug.to_raw_parts();
+    /// assert_eq!(<*const dyn Debug>::from_raw_parts(address, vtable), debug);
+    /// ```
+    #[unstable(feature = "ptr_metadata", issue = "81513")]
+    #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
+    #[inline]
+    pub const fn from_raw_parts(
+        data_address: *const (),
+        metadata: <T as super::Pointee>::Metadata,
+    ) -> Self {
+        super::from_raw_parts(data_address, metadata)
+    }
+
     /// Decompose a (possibly wide) pointer into is address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts`].
@@ -58,6 +217,38 @@ pub const fn to_raw_parts(self) -> (*const (), <T as super::Pointee>::Metadata)
         (self.cast(), metadata(self))
     }
 
+    /// Returns `None` if the pointer is null, or else returns the pointer wrapped in
+    /// a [`NonNull`].
+    ///
+    /// This is the same as [`NonNull::new`], but reads better at the end of a chain of
+    /// method calls, and can be used in const contexts.
+    ///
+    /// Like `NonNull` itself, the result is built on a `*mut T`: it is up to the caller not
+    /// to write through it unless the memory is actually mutable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_as_non_null)]
+    ///
+    /// let mut data = [1, 2, 3];
+    /// let ptr = data.as_ptr().as_non_null().unwrap();
+    /// assert_eq!(unsafe { *ptr.as_ptr() }, 1);
+    ///
+    /// assert!(std::ptr::null::<u8>().as_non_null().is_none());
+    /// ```
+    #[unstable(feature = "ptr_as_non_null", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_as_non_null", issue = "none")]
+    #[inline]
+    pub const fn as_non_null(self) -> Option<NonNull<T>> {
+        if self.is_null() {
+            None
+        } else {
+            // SAFETY: we just checked that the pointer is not null.
+            Some(unsafe { NonNull::new_unchecked(self as *mut T) })
+        }
+    }
+
     /// Returns `None` if the pointer is null, or else returns a shared reference to
     /// the value wrapped in `Some`. If the value may be uninitialized, [`as_uninit_ref`]
     /// must be used instead.
@@ -163,7 +354,8 @@ pub unsafe fn as_ref<'a>(self) -> Option<&'a T> {
     /// ```
     #[inline]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
-    pub unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
+    #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "none")]
+    pub const unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
     where
         T: Sized,
     {
@@ -231,6 +423,7 @@ pub unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
     where
         T: Sized,
     {
+        debug_assert_offset(self, count);
         // SAFETY: the caller must uphold the safety contract for `offset`.
         unsafe { intrinsics::offset(self, count) }
     }
@@ -293,6 +486,8 @@ pub const fn wrapping_offset(self, count: isize) -> *const T
     where
         T: Sized,
     {
+        #[cfg(feature = "debug_ptr_wrap")]
+        debug_check_wrap(self, count);
         // SAFETY: the `arith_offset` intrinsic has no prerequisites to be called.
         unsafe { intrinsics::arith_offset(self, count) }
     }
@@ -387,6 +582,21 @@ pub const fn wrapping_offset(self, count: isize) -> *const T
     {
         let pointee_size = mem::size_of::<T>();
         assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
+
+        fn runtime<T>(this: *const T, origin: *const T) {
+            debug_assert!(
+                same_allocation(this, origin),
+                "`offset_from` called on pointers into different allocations"
+            );
+        }
+
+        // The interpreter already rejects pointers into different allocations.
+        const fn comptime<T>(_: *const T, _: *const T) {}
+
+        // SAFETY: `runtime` only checks a precondition of `offset_from`, which the caller must
+        // uphold anyway.
+        unsafe { intrinsics::const_eval_select((self, origin), comptime, runtime) };
+
         // SAFETY: the caller must uphold the safety contract for `ptr_offset_from`.
         unsafe { intrinsics::ptr_offset_from(self, origin) }
     }
@@ -404,6 +614,9 @@ pub const fn wrapping_offset(self, count: isize) -> *const T
     ///
     /// [`guaranteed_ne`]: #method.guaranteed_ne
     ///
+    /// For wide pointers, the metadata (slice length or vtable) must be equal too. Slice lengths
+    /// are always compared exactly, so only the address part may spuriously compare unequal.
+    ///
     /// The return value may change depending on the compiler version and unsafe code may not
     /// rely on the result of this function for soundness. It is suggested to only use this function
     /// for performance optimizations where spurious `false` return values by this function do not
@@ -415,11 +628,9 @@ pub const fn wrapping_offset(self, count: isize) -> *const T
     #[unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[inline]
-    pub const fn guaranteed_eq(self, other: *const T) -> bool
-    where
-        T: Sized,
-    {
-        intrinsics::ptr_guaranteed_eq(self, other)
+    pub const fn guaranteed_eq(self, other: *const T) -> bool {
+        intrinsics::ptr_guaranteed_eq(self as *const u8, other as *const u8)
+            && metadata_guaranteed_eq(self, other)
     }
 
     /// Returns whether two pointers are guaranteed to be unequal.
@@ -435,6 +646,9 @@ pub const fn guaranteed_eq(self, other: *const T) -> bool
     ///
     /// [`guaranteed_eq`]: #method.guaranteed_eq
     ///
+    /// For wide pointers, the pointers are unequal if either their addresses or their metadata
+    /// (slice length or vtable) are guaranteed to differ.
+    ///
     /// The return value may change depending on the compiler version and unsafe code may not
     /// rely on the result of this function for soundness. It is suggested to only use this function
     /// for performance optimizations where spurious `false` return values by this function do not
@@ -446,11 +660,9 @@ pub const fn guaranteed_eq(self, other: *const T) -> bool
     #[unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[inline]
-    pub const fn guaranteed_ne(self, other: *const T) -> bool
-    where
-        T: Sized,
-    {
-        intrinsics::ptr_guaranteed_ne(self, other)
+    pub const fn guaranteed_ne(self, other: *const T) -> bool {
+        intrinsics::ptr_guaranteed_ne(self as *const u8, other as *const u8)
+            || metadata_guaranteed_ne(self, other)
     }
 
     /// Calculates the offset from a pointer (convenience for `.offset(count as isize)`).
@@ -704,6 +916,77 @@ pub const fn wrapping_sub(self, count: usize) -> Self
         self.wrapping_offset((count as isize).wrapping_neg())
     }
 
+    /// Calculates the offset from a pointer like [`wrapping_add`], but returns `None` if the
+    /// address would overflow.
+    ///
+    /// `count` is in units of T; e.g., a `count` of 3 represents a pointer
+    /// offset of `3 * size_of::<T>()` bytes.
+    ///
+    /// This is safe to call with any `count`: like [`wrapping_add`], it never dereferences
+    /// the pointer and keeps its provenance, so the result may still be out of bounds of the
+    /// allocated object `self` points to. It only guarantees that the address did not wrap
+    /// around.
+    ///
+    /// [`wrapping_add`]: #method.wrapping_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_checked_add)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let ptr = data.as_ptr();
+    /// assert_eq!(ptr.checked_add(2), Some(ptr.wrapping_add(2)));
+    /// assert_eq!(ptr.checked_add(usize::MAX), None);
+    /// ```
+    #[unstable(feature = "ptr_checked_add", issue = "none")]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    #[inline]
+    pub fn checked_add(self, count: usize) -> Option<Self>
+    where
+        T: Sized,
+    {
+        let bytes = count.checked_mul(crate::mem::size_of::<T>())?;
+        self.addr().checked_add(bytes)?;
+        Some(self.wrapping_add(count))
+    }
+
+    /// Calculates the offset from a pointer like [`wrapping_sub`], but returns `None` if the
+    /// address would underflow.
+    ///
+    /// `count` is in units of T; e.g., a `count` of 3 represents a pointer
+    /// offset of `3 * size_of::<T>()` bytes.
+    ///
+    /// This is safe to call with any `count`: like [`wrapping_sub`], it never dereferences
+    /// the pointer and keeps its provenance, so the result may still be out of bounds of the
+    /// allocated object `self` points to. It only guarantees that the address did not wrap
+    /// around.
+    ///
+    /// [`wrapping_sub`]: #method.wrapping_sub
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_checked_add)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let start = data.as_ptr();
+    /// let end = start.wrapping_add(3);
+    /// assert_eq!(end.checked_sub(3), Some(start));
+    /// assert_eq!(end.checked_sub(usize::MAX), None);
+    /// ```
+    #[unstable(feature = "ptr_checked_add", issue = "none")]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    #[inline]
+    pub fn checked_sub(self, count: usize) -> Option<Self>
+    where
+        T: Sized,
+    {
+        let bytes = count.checked_mul(crate::mem::size_of::<T>())?;
+        self.addr().checked_sub(bytes)?;
+        Some(self.wrapping_sub(count))
+    }
+
     /// Sets the pointer value to `ptr`.
     ///
     /// In case `self` is a (fat) pointer to an unsized type, this operation
@@ -758,6 +1041,7 @@ pub fn set_ptr_value(mut self, val: *const u8) -> Self {
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `read`.
         unsafe { read(self) }
     }
@@ -773,11 +1057,13 @@ pub fn set_ptr_value(mut self, val: *const u8) -> Self {
     ///
     /// [`ptr::read_volatile`]: crate::ptr::read_volatile()
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
     #[inline]
-    pub unsafe fn read_volatile(self) -> T
+    pub const unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `read_volatile`.
         unsafe { read_volatile(self) }
     }
@@ -801,6 +1087,41 @@ pub unsafe fn read_volatile(self) -> T
         unsafe { read_unaligned(self) }
     }
 
+    /// Reads the value `byte_offset` bytes past `self` without moving it.
+    ///
+    /// The resulting location may be unaligned. This is a shorthand for
+    /// `self.cast::<u8>().add(byte_offset).cast::<T>().read_unaligned()`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the safety contract of [`add`] for computing
+    /// `self.cast::<u8>().add(byte_offset)`, and that of [`ptr::read_unaligned`]
+    /// for reading from the resulting pointer.
+    ///
+    /// [`add`]: #method.add
+    /// [`ptr::read_unaligned`]: crate::ptr::read_unaligned()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_unaligned_at)]
+    ///
+    /// let bytes = [0u8, 0x78, 0x56, 0x34, 0x12, 0, 0, 0];
+    /// let base = bytes.as_ptr() as *const u32;
+    /// let value = unsafe { base.read_unaligned_at(1) };
+    /// assert_eq!(u32::from_le(value), 0x1234_5678);
+    /// ```
+    #[unstable(feature = "ptr_unaligned_at", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_read", issue = "80377")]
+    #[inline]
+    pub const unsafe fn read_unaligned_at(self, byte_offset: usize) -> T
+    where
+        T: Sized,
+    {
+        // SAFETY: the caller must uphold the safety contract for `add` and `read_unaligned`.
+        unsafe { self.cast::<u8>().add(byte_offset).cast::<T>().read_unaligned() }
+    }
+
     /// Copies `count * size_of<T>` bytes from `self` to `dest`. The source
     /// and destination may overlap.
     ///
@@ -816,6 +1137,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
+        debug_assert_aligned(dest);
         // SAFETY: the caller must uphold the safety contract for `copy`.
         unsafe { copy(self, dest, count) }
     }
@@ -835,6 +1158,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
+        debug_assert_aligned(dest);
         // SAFETY: the caller must uphold the safety contract for `copy_nonoverlapping`.
         unsafe { copy_nonoverlapping(self, dest, count) }
     }
@@ -847,6 +1172,10 @@ pub unsafe fn read_volatile(self) -> T
     /// return `usize::MAX`. Only your algorithm's performance can depend
     /// on getting a usable offset here, not its correctness.
     ///
+    /// During const evaluation, a usable offset is only returned if the allocation the pointer
+    /// points into is known to be at least as aligned as `align`, such as for pointers into a
+    /// suitably aligned `static`.
+    ///
     /// The offset is expressed in number of `T` elements, and not bytes. The value returned can be
     /// used with the `wrapping_add` method.
     ///
@@ -879,7 +1208,8 @@ pub unsafe fn read_volatile(self) -> T
     /// # } }
     /// ```
     #[stable(feature = "align_offset", since = "1.36.0")]
-    pub fn align_offset(self, align: usize) -> usize
+    #[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+    pub const fn align_offset(self, align: usize) -> usize
     where
         T: Sized,
     {
@@ -966,6 +1296,67 @@ pub unsafe fn get_unchecked<I>(self, index: I) -> *const I::Output
         unsafe { index.get_unchecked(self) }
     }
 
+    /// Splits the raw slice into a prefix, a middle raw slice of aligned `U`s, and a suffix,
+    /// like [`slice::align_to`] does for slices.
+    ///
+    /// Unlike the slice method, this only computes pointers and never reads the memory, so it
+    /// can be used on memory that is not initialized, or before it is known that a reference
+    /// to it could be created. The same lengths are returned as `slice::align_to` would
+    /// return, and `U`s are only placed in the middle part where they are aligned.
+    ///
+    /// This method has no purpose when either input element `T` or output element `U` are
+    /// zero-sized and will return the original raw slice without splitting anything.
+    ///
+    /// # Safety
+    ///
+    /// `self` must point into a single allocated object that spans at least
+    /// `self.len() * mem::size_of::<T>()` bytes, as required by [`add`].
+    ///
+    /// Reading the middle part is essentially a `transmute` with respect to its elements, so
+    /// all the usual caveats pertaining to `transmute::<T, U>` apply to any later accesses
+    /// through it.
+    ///
+    /// [`slice::align_to`]: ../../std/primitive.slice.html#method.align_to
+    /// [`add`]: #method.add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_ptr_align_to, slice_ptr_len)]
+    ///
+    /// let bytes = [0u8; 11];
+    /// let raw = &bytes[..] as *const [u8];
+    /// let (prefix, middle, suffix) = unsafe { raw.align_to::<u32>() };
+    /// assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 11);
+    /// assert_eq!(middle as *const u32 as usize % 4, 0);
+    /// ```
+    #[unstable(feature = "slice_ptr_align_to", issue = "none")]
+    #[rustc_const_unstable(feature = "const_slice_ptr_align_to", issue = "none")]
+    #[inline]
+    pub const unsafe fn align_to<U>(self) -> (*const [T], *const [U], *const [T]) {
+        let len = self.len();
+        let ptr = self.as_ptr();
+        let offset = if mem::size_of::<U>() == 0 || mem::size_of::<T>() == 0 {
+            usize::MAX
+        } else {
+            ptr.align_offset(mem::align_of::<U>())
+        };
+        if offset > len {
+            // Either a ZST is involved, or no `U` can be aligned within the slice.
+            let empty = slice_from_raw_parts(NonNull::<U>::dangling().as_ptr(), 0);
+            return (self, empty, slice_from_raw_parts(NonNull::dangling().as_ptr(), 0));
+        }
+        let (us_len, ts_len) = <[T]>::align_to_offsets::<U>(len - offset);
+        // SAFETY: both `offset` and `len - ts_len` are at most `len`, so the caller guarantees
+        // these stay within the same allocated object.
+        let (middle, suffix) = unsafe { (ptr.add(offset), ptr.add(len - ts_len)) };
+        (
+            slice_from_raw_parts(ptr, offset),
+            slice_from_raw_parts(middle as *const U, us_len),
+            slice_from_raw_parts(suffix, ts_len),
+        )
+    }
+
     /// Returns `None` if the pointer is null, or else returns a shared slice to
     /// the value wrapped in `Some`. In contrast to [`as_ref`], this does not require
     /// that the value has to be initialized.
@@ -1015,6 +1406,52 @@ pub unsafe fn as_uninit_slice<'a>(self) -> Option<&'a [MaybeUninit<T>]> {
     }
 }
 
+#[lang = "const_str_ptr"]
+impl *const str {
+    /// Returns the length of a raw string slice.
+    ///
+    /// The returned value is the number of **bytes**, not the number of characters.
+    ///
+    /// This function is safe, even when the raw string slice cannot be cast to a `&str`
+    /// because the pointer is null or the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(str_ptr_len)]
+    ///
+    /// let s = "héllo";
+    /// let ptr = s as *const str;
+    /// assert_eq!(ptr.len(), 6);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_ptr_len", issue = "none")]
+    #[rustc_const_unstable(feature = "str_ptr_len", issue = "none")]
+    pub const fn len(self) -> usize {
+        metadata(self)
+    }
+
+    /// Returns a raw pointer to the string slice's buffer.
+    ///
+    /// This is equivalent to casting `self` to `*const u8`, but more type-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(str_ptr_len)]
+    ///
+    /// let s = "hello";
+    /// let ptr = s as *const str;
+    /// assert_eq!(ptr.as_ptr(), s.as_ptr());
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_ptr_len", issue = "none")]
+    #[rustc_const_unstable(feature = "str_ptr_len", issue = "none")]
+    pub const fn as_ptr(self) -> *const u8 {
+        self as *const u8
+    }
+}
+
 // Equality for pointers
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> PartialEq for *const T {
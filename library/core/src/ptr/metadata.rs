@@ -2,6 +2,8 @@
 
 use crate::fmt;
 use crate::hash::{Hash, Hasher};
+use crate::intrinsics;
+use crate::mem;
 
 /// Provides the pointer metadata type of any pointed-to type.
 ///
@@ -134,6 +136,76 @@ pub const fn from_raw_parts_mut<T: ?Sized>(
     unsafe { PtrRepr { components: PtrComponents { data_address, metadata } }.mut_ptr }
 }
 
+/// Compares the metadata of two pointers, with the same "spurious `false`" semantics as
+/// [`intrinsics::ptr_guaranteed_eq`] during const evaluation.
+///
+/// Metadata is either zero-sized, a `usize`, or a single vtable reference, so outside of
+/// runtime it is reinterpreted as a thin pointer and compared with the intrinsic. Integer
+/// metadata such as slice lengths is therefore always compared exactly.
+#[inline]
+#[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
+pub(crate) const fn metadata_guaranteed_eq<T: ?Sized>(a: *const T, b: *const T) -> bool {
+    fn runtime<T: ?Sized>(a: *const T, b: *const T) -> bool {
+        metadata(a) == metadata(b)
+    }
+
+    #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
+    const fn comptime<T: ?Sized>(a: *const T, b: *const T) -> bool {
+        match metadata_as_addrs(a, b) {
+            Some((a, b)) => intrinsics::ptr_guaranteed_eq(a, b),
+            None => true,
+        }
+    }
+
+    // SAFETY: both functions compare the same values; `comptime` merely may return `false`
+    // where `runtime` would not, which callers of the `guaranteed_*` family must tolerate.
+    unsafe { intrinsics::const_eval_select((a, b), comptime, runtime) }
+}
+
+/// The inverse counterpart of [`metadata_guaranteed_eq`], mirroring
+/// [`intrinsics::ptr_guaranteed_ne`].
+#[inline]
+#[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
+pub(crate) const fn metadata_guaranteed_ne<T: ?Sized>(a: *const T, b: *const T) -> bool {
+    fn runtime<T: ?Sized>(a: *const T, b: *const T) -> bool {
+        metadata(a) != metadata(b)
+    }
+
+    #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
+    const fn comptime<T: ?Sized>(a: *const T, b: *const T) -> bool {
+        match metadata_as_addrs(a, b) {
+            Some((a, b)) => intrinsics::ptr_guaranteed_ne(a, b),
+            None => false,
+        }
+    }
+
+    // SAFETY: see `metadata_guaranteed_eq`.
+    unsafe { intrinsics::const_eval_select((a, b), comptime, runtime) }
+}
+
+/// Reinterprets the metadata of `a` and `b` as thin pointers, or returns `None` if the
+/// metadata is zero-sized.
+#[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
+const fn metadata_as_addrs<T: ?Sized>(a: *const T, b: *const T) -> Option<(*const u8, *const u8)> {
+    #[repr(C)]
+    union MetadataRepr<T: ?Sized> {
+        metadata: <T as Pointee>::Metadata,
+        addr: *const u8,
+    }
+
+    if mem::size_of::<<T as Pointee>::Metadata>() == 0 {
+        return None;
+    }
+    // SAFETY: non-zero-sized metadata is either a `usize` or a `&'static VTable`, both of which
+    // have the size and alignment of a thin pointer. Only std can make this guarantee.
+    unsafe {
+        Some((
+            MetadataRepr::<T> { metadata: metadata(a) }.addr,
+            MetadataRepr::<T> { metadata: metadata(b) }.addr,
+        ))
+    }
+}
+
 #[repr(C)]
 pub(crate) union PtrRepr<T: ?Sized> {
     pub(crate) const_ptr: *const T,
@@ -91,7 +91,7 @@
 pub use crate::intrinsics::write_bytes;
 
 mod metadata;
-pub(crate) use metadata::PtrRepr;
+pub(crate) use metadata::{metadata_guaranteed_eq, metadata_guaranteed_ne, PtrRepr};
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 pub use metadata::{from_raw_parts, from_raw_parts_mut, metadata, DynMetadata, Pointee, Thin};
 
@@ -235,6 +235,207 @@ pub const fn null_mut<T>() -> *mut T {
     0 as *mut T
 }
 
+/// Creates a null raw pointer that carries the given metadata.
+///
+/// For `T: Sized` this is the same as [`null`]. For unsized types this produces, for example, a
+/// null `*const [T]` with a given length or a null `*const dyn Trait` with a given vtable.
+///
+/// The resulting pointer is only useful as a sentinel value, for example in FFI tables. It is
+/// null and must never be dereferenced.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_metadata, ptr_null_with_metadata, slice_ptr_len)]
+/// use std::ptr;
+///
+/// let p: *const [u8] = ptr::null_with_metadata(3);
+/// assert!(p.is_null());
+/// assert_eq!(p.len(), 3);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_null_with_metadata", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_null_with_metadata", issue = "none")]
+pub const fn null_with_metadata<T: ?Sized>(metadata: <T as Pointee>::Metadata) -> *const T {
+    from_raw_parts(null(), metadata)
+}
+
+/// Creates a null mutable raw pointer that carries the given metadata.
+///
+/// This is the mutable counterpart of [`null_with_metadata`]. The resulting pointer is only
+/// useful as a sentinel value and must never be dereferenced.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_metadata, ptr_null_with_metadata, slice_ptr_len)]
+/// use std::ptr;
+///
+/// let p: *mut [u8] = ptr::null_mut_with_metadata(3);
+/// assert!(p.is_null());
+/// assert_eq!(p.len(), 3);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_null_with_metadata", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_null_with_metadata", issue = "none")]
+pub const fn null_mut_with_metadata<T: ?Sized>(metadata: <T as Pointee>::Metadata) -> *mut T {
+    from_raw_parts_mut(null_mut(), metadata)
+}
+
+/// Converts an address back into a pointer, picking up any provenance that has previously
+/// been exposed.
+///
+/// This is equivalent to `addr as *const T`. It is the counterpart of
+/// [`expose_addr`](pointer::expose_addr): an integer obtained from `expose_addr` may be turned
+/// back into a pointer that is allowed to access the same memory as the original one. Integers
+/// that were never exposed, such as the result of [`addr`](pointer::addr), produce a pointer
+/// without provenance that must not be used for memory accesses; use
+/// [`with_addr`](pointer::with_addr) for those instead.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let data = [1u32, 2, 3];
+/// let addr = data.as_ptr().expose_addr();
+/// let last: *const u32 = ptr::with_exposed_provenance(addr + 2 * std::mem::size_of::<u32>());
+/// assert_eq!(unsafe { *last }, 3);
+/// ```
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub fn with_exposed_provenance<T>(addr: usize) -> *const T {
+    addr as *const T
+}
+
+/// Converts an address back into a mutable pointer, picking up any provenance that has
+/// previously been exposed.
+///
+/// This is equivalent to `addr as *mut T`. See [`with_exposed_provenance`] for details.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(strict_provenance)]
+/// use std::ptr;
+///
+/// let mut data = [1u32, 2, 3];
+/// let addr = data.as_mut_ptr().expose_addr();
+/// let first: *mut u32 = ptr::with_exposed_provenance_mut(addr);
+/// unsafe { *first = 7 };
+/// assert_eq!(data, [7, 2, 3]);
+/// ```
+#[inline(always)]
+#[unstable(feature = "strict_provenance", issue = "none")]
+pub fn with_exposed_provenance_mut<T>(addr: usize) -> *mut T {
+    addr as *mut T
+}
+
+/// Converts a reference to a raw pointer.
+///
+/// This is equivalent to `r as *const T`, but is a bit safer since it will never silently
+/// change the type or mutability, in particular if the code is refactored. The metadata of
+/// unsized types (slice lengths, vtables) is kept.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_from_ref)]
+/// use std::ptr;
+///
+/// let data = [1, 2, 3];
+/// let p: *const [i32] = ptr::from_ref(&data[..]);
+/// assert_eq!(unsafe { &*p }, &[1, 2, 3]);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_from_ref", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_from_ref", issue = "none")]
+pub const fn from_ref<T: ?Sized>(r: &T) -> *const T {
+    r
+}
+
+/// Converts a mutable reference to a raw pointer.
+///
+/// This is equivalent to `r as *mut T`, but is a bit safer since it will never silently
+/// change the type or mutability, in particular if the code is refactored. The metadata of
+/// unsized types (slice lengths, vtables) is kept.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_from_ref)]
+/// use std::ptr;
+///
+/// let mut x = 5;
+/// let p: *mut i32 = ptr::from_mut(&mut x);
+/// unsafe { *p += 1 };
+/// assert_eq!(x, 6);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_from_ref", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_from_ref", issue = "none")]
+pub const fn from_mut<T: ?Sized>(r: &mut T) -> *mut T {
+    r
+}
+
+/// Returns a raw pointer to the first element of `slice` that may be used to access the whole
+/// slice.
+///
+/// This is the same as [`slice.as_ptr()`], spelled so that it is an obvious replacement for
+/// `&slice[0] as *const T`. The latter creates a reference to the first element
+/// only, and a pointer derived from it may only be used to access that element: offsetting it
+/// to reach the other elements is undefined behavior under Stacked Borrows. The pointer
+/// returned here may be offset and read anywhere within `slice`, and one past its end.
+///
+/// If `slice` is empty the pointer is still non-null and aligned, but may not be dereferenced.
+///
+/// [`slice.as_ptr()`]: ../../std/primitive.slice.html#method.as_ptr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_addr_of_first)]
+/// use std::ptr;
+///
+/// let data = [1, 2, 3];
+/// let first = ptr::addr_of_first(&data);
+/// assert_eq!(unsafe { *first.add(2) }, 3);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_addr_of_first", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_addr_of_first", issue = "none")]
+pub const fn addr_of_first<T>(slice: &[T]) -> *const T {
+    slice.as_ptr()
+}
+
+/// Returns a mutable raw pointer to the first element of `slice` that may be used to access the
+/// whole slice.
+///
+/// This is the same as [`slice.as_mut_ptr()`], and the mutable counterpart of
+/// [`addr_of_first`]. Unlike `&mut slice[0] as *mut T`, the returned pointer may be used to read
+/// and write any element of `slice`.
+///
+/// [`slice.as_mut_ptr()`]: ../../std/primitive.slice.html#method.as_mut_ptr
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_addr_of_first)]
+/// use std::ptr;
+///
+/// let mut data = [1, 2, 3];
+/// let first = ptr::addr_of_first_mut(&mut data);
+/// unsafe { *first.add(2) = 4 };
+/// assert_eq!(data, [1, 2, 4]);
+/// ```
+#[inline(always)]
+#[unstable(feature = "ptr_addr_of_first", issue = "none")]
+#[rustc_const_unstable(feature = "ptr_addr_of_first", issue = "none")]
+pub const fn addr_of_first_mut<T>(slice: &mut [T]) -> *mut T {
+    slice.as_mut_ptr()
+}
+
 /// Forms a raw slice from a pointer and a length.
 ///
 /// The `len` argument is the number of **elements**, not the number of bytes.
@@ -242,6 +443,10 @@ pub const fn null_mut<T>() -> *mut T {
 /// This function is safe, but actually using the return value is unsafe.
 /// See the documentation of [`slice::from_raw_parts`] for slice safety requirements.
 ///
+/// The total size of the slice, `len * mem::size_of::<T>()`, must not exceed `isize::MAX`
+/// bytes. This is checked during const evaluation, and at runtime when debug assertions
+/// are enabled.
+///
 /// [`slice::from_raw_parts`]: crate::slice::from_raw_parts
 ///
 /// # Examples
@@ -259,6 +464,7 @@ pub const fn null_mut<T>() -> *mut T {
 #[stable(feature = "slice_from_raw_parts", since = "1.42.0")]
 #[rustc_const_unstable(feature = "const_slice_from_raw_parts", issue = "67456")]
 pub const fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T] {
+    debug_assert_slice_len::<T>(len);
     from_raw_parts(data.cast(), len)
 }
 
@@ -291,6 +497,7 @@ pub const fn slice_from_raw_parts<T>(data: *const T, len: usize) -> *const [T] {
 #[stable(feature = "slice_from_raw_parts", since = "1.42.0")]
 #[rustc_const_unstable(feature = "const_slice_from_raw_parts", issue = "67456")]
 pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
+    debug_assert_slice_len::<T>(len);
     from_raw_parts_mut(data.cast(), len)
 }
 
@@ -1006,6 +1213,10 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 /// (e.g., if a zero-sized type is passed to `read_volatile`) are noops
 /// and may be ignored.
 ///
+/// During const evaluation there is no I/O memory, so `read_volatile` behaves like
+/// [`read`] there. Accessing anything but memory owned by the constant being evaluated
+/// (such as a mutable `static`) is still rejected by the compiler.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
@@ -1049,13 +1260,26 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 /// ```
 #[inline]
 #[stable(feature = "volatile", since = "1.9.0")]
-pub unsafe fn read_volatile<T>(src: *const T) -> T {
-    if cfg!(debug_assertions) && !is_aligned_and_not_null(src) {
-        // Not panicking to keep codegen impact smaller.
-        abort();
+#[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
+pub const unsafe fn read_volatile<T>(src: *const T) -> T {
+    fn runtime<T>(src: *const T) -> T {
+        if cfg!(debug_assertions) && !is_aligned_and_not_null(src) {
+            // Not panicking to keep codegen impact smaller.
+            abort();
+        }
+        // SAFETY: the caller must uphold the safety contract for `volatile_load`.
+        unsafe { intrinsics::volatile_load(src) }
+    }
+
+    #[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
+    const fn comptime<T>(src: *const T) -> T {
+        // SAFETY: the caller must uphold the safety contract for `read`.
+        unsafe { read(src) }
     }
-    // SAFETY: the caller must uphold the safety contract for `volatile_load`.
-    unsafe { intrinsics::volatile_load(src) }
+
+    // SAFETY: there is no I/O memory during const evaluation, so a plain read there
+    // has the same effect as the volatile one at runtime.
+    unsafe { intrinsics::const_eval_select((src,), comptime, runtime) }
 }
 
 /// Performs a volatile write of a memory location with the given value without
@@ -1084,6 +1308,10 @@ pub unsafe fn read_volatile<T>(src: *const T) -> T {
 /// (e.g., if a zero-sized type is passed to `write_volatile`) are noops
 /// and may be ignored.
 ///
+/// During const evaluation there is no I/O memory, so `write_volatile` behaves like
+/// [`write`] there. Accessing anything but memory owned by the constant being evaluated
+/// (such as a mutable `static`) is still rejected by the compiler.
+///
 /// [c11]: http://www.open-std.org/jtc1/sc22/wg14/www/docs/n1570.pdf
 ///
 /// # Safety
@@ -1120,15 +1348,141 @@ pub unsafe fn read_volatile<T>(src: *const T) -> T {
 /// ```
 #[inline]
 #[stable(feature = "volatile", since = "1.9.0")]
-pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
-    if cfg!(debug_assertions) && !is_aligned_and_not_null(dst) {
-        // Not panicking to keep codegen impact smaller.
-        abort();
+#[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
+pub const unsafe fn write_volatile<T>(dst: *mut T, src: T) {
+    fn runtime<T>(dst: *mut T, src: T) {
+        if cfg!(debug_assertions) && !is_aligned_and_not_null(dst) {
+            // Not panicking to keep codegen impact smaller.
+            abort();
+        }
+        // SAFETY: the caller must uphold the safety contract for `volatile_store`.
+        unsafe {
+            intrinsics::volatile_store(dst, src);
+        }
     }
-    // SAFETY: the caller must uphold the safety contract for `volatile_store`.
-    unsafe {
-        intrinsics::volatile_store(dst, src);
+
+    #[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
+    const fn comptime<T>(dst: *mut T, src: T) {
+        // SAFETY: the caller must uphold the safety contract for `write`.
+        unsafe { write(dst, src) }
+    }
+
+    // SAFETY: there is no I/O memory during const evaluation, so a plain write there
+    // has the same effect as the volatile one at runtime.
+    unsafe { intrinsics::const_eval_select((dst, src), comptime, runtime) }
+}
+
+/// Asserts in debug builds that `ptr` is aligned for `T`.
+///
+/// This backs the alignment checks of the pointer method wrappers. Zero-sized
+/// types are never checked, and neither is anything during const evaluation,
+/// where the interpreter already rejects misaligned accesses on its own.
+#[inline]
+#[rustc_const_unstable(feature = "const_eval_select", issue = "none")]
+pub(crate) const fn debug_assert_aligned<T>(ptr: *const T) {
+    fn runtime<T>(ptr: *const T) {
+        assert!(
+            mem::size_of::<T>() == 0 || ptr as usize % mem::align_of::<T>() == 0,
+            "attempt to access unaligned pointer"
+        );
+    }
+
+    const fn comptime<T>(_: *const T) {}
+
+    if cfg!(debug_assertions) {
+        // SAFETY: `runtime` only observes the address, and a misaligned pointer
+        // makes the caller's access UB regardless of which function runs.
+        unsafe { intrinsics::const_eval_select((ptr,), comptime, runtime) }
+    }
+}
+
+/// Asserts in debug builds that offsetting `ptr` by `count` elements stays within the address
+/// space.
+///
+/// This backs the [`offset`](pointer::offset) family of pointer methods. The bounds of the
+/// allocated object are not known here, and [`same_allocation`] always returns `true` in
+/// compiled code, so this does not check that the result stays in bounds of the allocation.
+/// Only offsets that cannot possibly stay in bounds of any allocation are caught: those whose
+/// size in bytes overflows an `isize`, or that wrap around the address space. Pointers one past
+/// the end of an object are therefore never flagged, but neither are offsets that leave a small
+/// object without wrapping, like `add(100)` on a four-element array. Nothing is checked during
+/// const evaluation, where the interpreter knows the exact bounds.
+#[inline]
+#[rustc_const_unstable(feature = "const_ptr_offset", issue = "71499")]
+pub(crate) const fn debug_assert_offset<T>(ptr: *const T, count: isize) {
+    fn runtime<T>(ptr: *const T, count: isize) {
+        let addr = ptr as usize;
+        let in_range = match count.checked_mul(mem::size_of::<T>() as isize) {
+            Some(bytes) if bytes >= 0 => addr.checked_add(bytes as usize).is_some(),
+            Some(bytes) => addr.checked_sub(bytes.unsigned_abs()).is_some(),
+            None => false,
+        };
+        assert!(in_range, "`offset` called with a count that overflows the address space");
+    }
+
+    const fn comptime<T>(_: *const T, _: isize) {}
+
+    if cfg!(debug_assertions) {
+        // SAFETY: `runtime` only observes the address, and an out-of-range offset makes the
+        // caller's offset UB regardless of which function runs.
+        unsafe { intrinsics::const_eval_select((ptr, count), comptime, runtime) }
+    }
+}
+
+/// Checks that a slice of `len` elements of type `T` is no larger than `isize::MAX` bytes.
+///
+/// This backs the length checks of the raw slice constructors. Unlike for
+/// [`debug_assert_aligned`], nothing else would catch an over-long slice pointer during const
+/// evaluation, so it is always checked there.
+#[inline]
+#[rustc_const_unstable(feature = "const_slice_from_raw_parts", issue = "67456")]
+pub(crate) const fn debug_assert_slice_len<T>(len: usize) {
+    const fn fits<T>(len: usize) -> bool {
+        match len.checked_mul(mem::size_of::<T>()) {
+            Some(size) => size <= isize::MAX as usize,
+            None => false,
+        }
     }
+
+    fn runtime<T>(len: usize) {
+        if cfg!(debug_assertions) {
+            assert!(fits::<T>(len), "slice pointer length exceeds `isize::MAX` bytes");
+        }
+    }
+
+    #[rustc_const_unstable(feature = "const_slice_from_raw_parts", issue = "67456")]
+    const fn comptime<T>(len: usize) {
+        assert!(fits::<T>(len), "slice pointer length exceeds `isize::MAX` bytes");
+    }
+
+    // SAFETY: both functions only check `len`, and a compile-time error is not
+    // observable at runtime.
+    unsafe { intrinsics::const_eval_select((len,), comptime::<T>, runtime::<T>) }
+}
+
+/// Panics if offsetting `ptr` by `count` elements of `T` wraps around the address space.
+///
+/// This backs the `debug_ptr_wrap` feature. Wrapping pointer arithmetic is not UB, but when
+/// fuzzing unsafe code a wrap is usually a logic bug worth hearing about. Const evaluation cannot
+/// observe addresses, so there this is a no-op.
+#[cfg(feature = "debug_ptr_wrap")]
+#[inline]
+#[rustc_const_unstable(feature = "const_ptr_offset", issue = "71499")]
+pub(crate) const fn debug_check_wrap<T>(ptr: *const T, count: isize) {
+    fn runtime<T>(ptr: *const T, count: isize) {
+        let addr = ptr as usize;
+        let wrapped = match count.checked_mul(mem::size_of::<T>() as isize) {
+            Some(bytes) if bytes >= 0 => addr.checked_add(bytes as usize).is_none(),
+            Some(bytes) => addr.checked_sub(bytes.unsigned_abs()).is_none(),
+            None => true,
+        };
+        assert!(!wrapped, "pointer arithmetic wrapped around the address space");
+    }
+
+    const fn comptime<T>(_: *const T, _: isize) {}
+
+    // SAFETY: `runtime` only panics, and that is not observable in const evaluation.
+    unsafe { intrinsics::const_eval_select((ptr, count), comptime::<T>, runtime::<T>) }
 }
 
 /// Align pointer `p`.
@@ -1146,7 +1500,25 @@ pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
 ///
 /// Any questions go to @nagisa.
 #[lang = "align_offset"]
-pub(crate) unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
+#[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+pub(crate) const unsafe fn align_offset<T: Sized>(p: *const T, a: usize) -> usize {
+    fn runtime<T>(p: *const T, a: usize) -> usize {
+        // SAFETY: the caller of `align_offset` guarantees that `a` is a power of two.
+        unsafe { align_offset_rt(p, a) }
+    }
+
+    // The interpreter intercepts calls to `align_offset` during const evaluation, since only it
+    // knows how the pointer's allocation is aligned. Should that not happen, give up.
+    const fn comptime<T>(_: *const T, _: usize) -> usize {
+        usize::MAX
+    }
+
+    // SAFETY: `usize::MAX` is always a permissible answer, so both functions are correct.
+    unsafe { intrinsics::const_eval_select((p, a), comptime, runtime) }
+}
+
+/// The runtime implementation of [`align_offset`], which needs the address of `p`.
+unsafe fn align_offset_rt<T: Sized>(p: *const T, a: usize) -> usize {
     // FIXME(#75598): Direct use of these intrinsics improves codegen significantly at opt-level <=
     // 1, where the method versions of these operations are not inlined.
     use intrinsics::{
@@ -1358,6 +1730,56 @@ pub fn eq<T: ?Sized>(a: *const T, b: *const T) -> bool {
     a == b
 }
 
+/// Compares the *addresses* of the two pointers for equality,
+/// ignoring any metadata in fat pointers.
+///
+/// If the arguments are thin pointers of the same type,
+/// then this is the same as [`eq`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_addr_eq)]
+/// use std::ptr;
+///
+/// let whole: &[i32; 3] = &[1, 2, 3];
+/// let first: &i32 = &whole[0];
+///
+/// assert!(ptr::addr_eq(whole, first));
+/// assert!(!ptr::eq::<dyn std::fmt::Debug>(whole, first));
+/// ```
+#[unstable(feature = "ptr_addr_eq", issue = "none")]
+#[inline]
+pub fn addr_eq<T: ?Sized, U: ?Sized>(p: *const T, q: *const U) -> bool {
+    (p as *const ()) == (q as *const ())
+}
+
+/// Returns `false` if the two pointers are known to be derived from different allocated
+/// objects, and `true` otherwise.
+///
+/// This is the precondition of [`offset_from`](pointer::offset_from), made available as a
+/// predicate for debug assertions. Compiled code does not track provenance, so there
+/// this always returns `true`. Only tools that do, like Miri, can actually detect pointers
+/// into different allocations.
+///
+/// Like [`align_offset`](pointer::align_offset), this must not be relied on for
+/// correctness: a `true` result proves nothing.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(ptr_same_allocation)]
+/// use std::ptr;
+///
+/// let data = [1, 2, 3];
+/// assert!(ptr::same_allocation(&data[0], &data[2]));
+/// ```
+#[unstable(feature = "ptr_same_allocation", issue = "none")]
+#[inline]
+pub fn same_allocation<T>(a: *const T, b: *const T) -> bool {
+    intrinsics::ptr_same_allocation(a, b)
+}
+
 /// Hash a raw pointer.
 ///
 /// This can be used to hash a `&T` reference (which coerces to `*const T` implicitly)
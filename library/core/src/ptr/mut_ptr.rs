@@ -1,6 +1,7 @@
 use super::*;
 use crate::cmp::Ordering::{self, Equal, Greater, Less};
 use crate::intrinsics;
+use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 
 #[lang = "mut_ptr"]
@@ -21,6 +22,10 @@ impl<T: ?Sized> *mut T {
     /// the absolute position of that memory, so we cannot tell if the pointer is
     /// null or not.
     ///
+    /// The answer is exact for pointers created from integers, such as
+    /// [`null`](crate::ptr::null), and for pointers that are in bounds of the memory they
+    /// point to, such as references to `static` or `const` items.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -47,6 +52,162 @@ pub const fn cast<U>(self) -> *mut U {
         self as _
     }
 
+    /// Casts to a pointer of another type, asserting in debug builds that the pointer is
+    /// aligned for the new type.
+    ///
+    /// This behaves like [`cast`](#method.cast), but catches the common mistake of casting a
+    /// byte pointer to a pointer to a larger type at an address that is not suitably aligned
+    /// for it. Pointers to zero-sized types are not checked, and nothing is checked during
+    /// const evaluation, where misaligned accesses are rejected on their own.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be aligned to `align_of::<U>()`. Violating this is only detected in builds
+    /// of the standard library with debug assertions enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_cast_aligned)]
+    ///
+    /// let mut words = [1u32, 2];
+    /// let bytes = words.as_mut_ptr().cast::<u8>();
+    /// let second = unsafe { bytes.add(4).cast_aligned::<u32>() };
+    /// unsafe { second.write(7) };
+    /// assert_eq!(words, [1, 7]);
+    /// ```
+    #[unstable(feature = "ptr_cast_aligned", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_cast_aligned", issue = "none")]
+    #[inline]
+    pub const unsafe fn cast_aligned<U>(self) -> *mut U {
+        let ptr = self.cast::<U>();
+        debug_assert_aligned(ptr);
+        ptr
+    }
+
+    /// Gets the address portion of the pointer.
+    ///
+    /// Unlike `self as usize`, this does not *expose* the provenance of the pointer: the
+    /// returned integer only describes where the pointer points, and turning it back into
+    /// a pointer does not give back permission to access the memory it pointed to. Use
+    /// [`expose_addr`](#method.expose_addr) when the integer must be convertible back into
+    /// a usable pointer.
+    ///
+    /// For fat pointers, only the data address is returned and the metadata is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = 5u32;
+    /// let ptr = &mut x as *mut u32;
+    /// assert_eq!(ptr.addr(), ptr.expose_addr());
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn addr(self) -> usize {
+        // Go through `()` so that fat pointers only contribute their data address.
+        intrinsics::ptr_addr(self.cast::<()>())
+    }
+
+    /// Gets the address portion of the pointer and exposes its provenance.
+    ///
+    /// This is equivalent to `self as usize`: integers obtained this way may later be
+    /// cast back into a pointer that is allowed to access the same memory. Prefer
+    /// [`addr`](#method.addr) whenever only the address itself is needed, as exposing
+    /// provenance hinders alias analysis and is not supported on every target.
+    ///
+    /// For fat pointers, only the data address is returned and the metadata is discarded.
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn expose_addr(self) -> usize {
+        self.cast::<()>() as usize
+    }
+
+    /// Creates a new pointer with the given address and the provenance (and, for fat
+    /// pointers, the metadata) of `self`.
+    ///
+    /// This is the strict-provenance way of changing the address of a pointer: unlike
+    /// casting an integer into a pointer, the result may still be used to access the memory
+    /// `self` is allowed to access, as long as the new address is in bounds of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let ptr = data.as_ptr() as *mut u16;
+    /// let last = ptr.with_addr(ptr.addr() + 2 * std::mem::size_of::<u16>());
+    /// assert_eq!(unsafe { *last }, 3);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn with_addr(self, addr: usize) -> Self {
+        // Offset `self` instead of casting `addr`, so that the provenance is kept. The offset
+        // may wrap around, since the new address is not required to be in bounds, so this
+        // bypasses the `debug_ptr_wrap` check in `wrapping_offset`.
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        // SAFETY: the `arith_offset` intrinsic has no prerequisites to be called.
+        let ptr = unsafe { intrinsics::arith_offset(self.cast::<u8>(), offset) as *mut u8 };
+        self.set_ptr_value(ptr)
+    }
+
+    /// Creates a new pointer by mapping the address of `self` to a new one, keeping the
+    /// provenance of `self`.
+    ///
+    /// This is a convenience for [`with_addr`](#method.with_addr), mostly useful for
+    /// tagging and untagging the low bits of aligned pointers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    ///
+    /// let mut x = 7u32;
+    /// let ptr = &mut x as *mut u32;
+    /// let tagged = ptr.map_addr(|addr| addr | 1);
+    /// let untagged = tagged.map_addr(|addr| addr & !1);
+    /// assert_eq!(unsafe { *untagged }, 7);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self {
+        self.with_addr(f(self.addr()))
+    }
+
+    /// Forms a (possibly wide) raw pointer from a data address and metadata.
+    ///
+    /// This is the inverse of [`to_raw_parts`](#method.to_raw_parts), and the same as the
+    /// [`from_raw_parts_mut`] free function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_metadata)]
+    /// use std::fmt::Debug;
+    ///
+    /// let mut data = [1u8, 2, 3];
+    /// let slice = &mut data[..] as *mut [u8];
+    /// let (address, len) = slice.to_raw_parts();
+    /// assert_eq!(<*mut [u8]>::from_raw_parts(address, len), slice);
+    ///
+    /// let mut value = 5u32;
+    /// let debug = &mut value as *mut dyn Debug;
+    /// let (address, vtable) = debug.to_raw_parts();
+    /// assert_eq!(<*mut dyn Debug>::from_raw_parts(address, vtable), debug);
+    /// ```
+    #[unstable(feature = "ptr_metadata", issue = "81513")]
+    #[rustc_const_unstable(feature = "ptr_metadata", issue = "81513")]
+    #[inline]
+    pub const fn from_raw_parts(
+        data_address: *mut (),
+        metadata: <T as super::Pointee>::Metadata,
+    ) -> Self {
+        super::from_raw_parts_mut(data_address, metadata)
+    }
+
     /// Decompose a (possibly wide) pointer into is address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts_mut`].
@@ -57,6 +218,35 @@ pub const fn to_raw_parts(self) -> (*mut (), <T as super::Pointee>::Metadata) {
         (self.cast(), super::metadata(self))
     }
 
+    /// Returns `None` if the pointer is null, or else returns the pointer wrapped in
+    /// a [`NonNull`].
+    ///
+    /// This is the same as [`NonNull::new`], but reads better at the end of a chain of
+    /// method calls, and can be used in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_as_non_null)]
+    ///
+    /// let mut data = [1, 2, 3];
+    /// let ptr = data.as_mut_ptr().as_non_null().unwrap();
+    /// assert_eq!(unsafe { *ptr.as_ptr() }, 1);
+    ///
+    /// assert!(std::ptr::null_mut::<u8>().as_non_null().is_none());
+    /// ```
+    #[unstable(feature = "ptr_as_non_null", issue = "none")]
+    #[rustc_const_unstable(feature = "ptr_as_non_null", issue = "none")]
+    #[inline]
+    pub const fn as_non_null(self) -> Option<NonNull<T>> {
+        if self.is_null() {
+            None
+        } else {
+            // SAFETY: we just checked that the pointer is not null.
+            Some(unsafe { NonNull::new_unchecked(self as *mut T) })
+        }
+    }
+
     /// Returns `None` if the pointer is null, or else returns a shared reference to
     /// the value wrapped in `Some`. If the value may be uninitialized, [`as_uninit_ref`]
     /// must be used instead.
@@ -168,7 +358,8 @@ pub unsafe fn as_ref<'a>(self) -> Option<&'a T> {
     /// ```
     #[inline]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
-    pub unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
+    #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "none")]
+    pub const unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
     where
         T: Sized,
     {
@@ -236,6 +427,7 @@ pub unsafe fn as_uninit_ref<'a>(self) -> Option<&'a MaybeUninit<T>>
     where
         T: Sized,
     {
+        debug_assert_offset(self, count);
         // SAFETY: the caller must uphold the safety contract for `offset`.
         // The obtained pointer is valid for writes since the caller must
         // guarantee that it points to the same allocated object as `self`.
@@ -299,6 +491,8 @@ pub const fn wrapping_offset(self, count: isize) -> *mut T
     where
         T: Sized,
     {
+        #[cfg(feature = "debug_ptr_wrap")]
+        debug_check_wrap(self as *const T, count);
         // SAFETY: the `arith_offset` intrinsic has no prerequisites to be called.
         unsafe { intrinsics::arith_offset(self, count) as *mut T }
     }
@@ -395,9 +589,29 @@ pub unsafe fn as_mut<'a>(self) -> Option<&'a mut T> {
     /// This applies even if the result of this method is unused!
     ///
     /// [the module documentation]: crate::ptr#safety
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// #![feature(ptr_as_uninit)]
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut x = MaybeUninit::<u32>::uninit();
+    /// let ptr: *mut u32 = x.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     if let Some(slot) = ptr.as_uninit_mut() {
+    ///         slot.write(7);
+    ///     }
+    ///     assert_eq!(x.assume_init(), 7);
+    /// }
+    /// ```
     #[inline]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
-    pub unsafe fn as_uninit_mut<'a>(self) -> Option<&'a mut MaybeUninit<T>>
+    #[rustc_const_unstable(feature = "const_ptr_as_ref", issue = "none")]
+    pub const unsafe fn as_uninit_mut<'a>(self) -> Option<&'a mut MaybeUninit<T>>
     where
         T: Sized,
     {
@@ -419,6 +633,9 @@ pub unsafe fn as_uninit_mut<'a>(self) -> Option<&'a mut MaybeUninit<T>>
     ///
     /// [`guaranteed_ne`]: #method.guaranteed_ne
     ///
+    /// For wide pointers, the metadata (slice length or vtable) must be equal too. Slice lengths
+    /// are always compared exactly, so only the address part may spuriously compare unequal.
+    ///
     /// The return value may change depending on the compiler version and unsafe code may not
     /// rely on the result of this function for soundness. It is suggested to only use this function
     /// for performance optimizations where spurious `false` return values by this function do not
@@ -430,11 +647,8 @@ pub unsafe fn as_uninit_mut<'a>(self) -> Option<&'a mut MaybeUninit<T>>
     #[unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[inline]
-    pub const fn guaranteed_eq(self, other: *mut T) -> bool
-    where
-        T: Sized,
-    {
-        intrinsics::ptr_guaranteed_eq(self as *const _, other as *const _)
+    pub const fn guaranteed_eq(self, other: *mut T) -> bool {
+        (self as *const T).guaranteed_eq(other as *const T)
     }
 
     /// Returns whether two pointers are guaranteed to be unequal.
@@ -450,6 +664,9 @@ pub const fn guaranteed_eq(self, other: *mut T) -> bool
     ///
     /// [`guaranteed_eq`]: #method.guaranteed_eq
     ///
+    /// For wide pointers, the pointers are unequal if either their addresses or their metadata
+    /// (slice length or vtable) are guaranteed to differ.
+    ///
     /// The return value may change depending on the compiler version and unsafe code may not
     /// rely on the result of this function for soundness. It is suggested to only use this function
     /// for performance optimizations where spurious `false` return values by this function do not
@@ -461,11 +678,8 @@ pub const fn guaranteed_eq(self, other: *mut T) -> bool
     #[unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[rustc_const_unstable(feature = "const_raw_ptr_comparison", issue = "53020")]
     #[inline]
-    pub const unsafe fn guaranteed_ne(self, other: *mut T) -> bool
-    where
-        T: Sized,
-    {
-        intrinsics::ptr_guaranteed_ne(self as *const _, other as *const _)
+    pub const unsafe fn guaranteed_ne(self, other: *mut T) -> bool {
+        (self as *const T).guaranteed_ne(other as *const T)
     }
 
     /// Calculates the distance between two pointers. The returned value is in
@@ -810,6 +1024,77 @@ pub const fn wrapping_sub(self, count: usize) -> Self
         self.wrapping_offset((count as isize).wrapping_neg())
     }
 
+    /// Calculates the offset from a pointer like [`wrapping_add`], but returns `None` if the
+    /// address would overflow.
+    ///
+    /// `count` is in units of T; e.g., a `count` of 3 represents a pointer
+    /// offset of `3 * size_of::<T>()` bytes.
+    ///
+    /// This is safe to call with any `count`: like [`wrapping_add`], it never dereferences
+    /// the pointer and keeps its provenance, so the result may still be out of bounds of the
+    /// allocated object `self` points to. It only guarantees that the address did not wrap
+    /// around.
+    ///
+    /// [`wrapping_add`]: #method.wrapping_add
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_checked_add)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let ptr = data.as_ptr() as *mut u16;
+    /// assert_eq!(ptr.checked_add(2), Some(ptr.wrapping_add(2)));
+    /// assert_eq!(ptr.checked_add(usize::MAX), None);
+    /// ```
+    #[unstable(feature = "ptr_checked_add", issue = "none")]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    #[inline]
+    pub fn checked_add(self, count: usize) -> Option<Self>
+    where
+        T: Sized,
+    {
+        let bytes = count.checked_mul(crate::mem::size_of::<T>())?;
+        self.addr().checked_add(bytes)?;
+        Some(self.wrapping_add(count))
+    }
+
+    /// Calculates the offset from a pointer like [`wrapping_sub`], but returns `None` if the
+    /// address would underflow.
+    ///
+    /// `count` is in units of T; e.g., a `count` of 3 represents a pointer
+    /// offset of `3 * size_of::<T>()` bytes.
+    ///
+    /// This is safe to call with any `count`: like [`wrapping_sub`], it never dereferences
+    /// the pointer and keeps its provenance, so the result may still be out of bounds of the
+    /// allocated object `self` points to. It only guarantees that the address did not wrap
+    /// around.
+    ///
+    /// [`wrapping_sub`]: #method.wrapping_sub
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_checked_add)]
+    ///
+    /// let data = [1u16, 2, 3];
+    /// let start = data.as_ptr() as *mut u16;
+    /// let end = start.wrapping_add(3);
+    /// assert_eq!(end.checked_sub(3), Some(start));
+    /// assert_eq!(end.checked_sub(usize::MAX), None);
+    /// ```
+    #[unstable(feature = "ptr_checked_add", issue = "none")]
+    #[must_use = "returns a new pointer rather than modifying its argument"]
+    #[inline]
+    pub fn checked_sub(self, count: usize) -> Option<Self>
+    where
+        T: Sized,
+    {
+        let bytes = count.checked_mul(crate::mem::size_of::<T>())?;
+        self.addr().checked_sub(bytes)?;
+        Some(self.wrapping_sub(count))
+    }
+
     /// Sets the pointer value to `ptr`.
     ///
     /// In case `self` is a (fat) pointer to an unsized type, this operation
@@ -864,6 +1149,7 @@ pub fn set_ptr_value(mut self, val: *mut u8) -> Self {
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for ``.
         unsafe { read(self) }
     }
@@ -879,11 +1165,13 @@ pub fn set_ptr_value(mut self, val: *mut u8) -> Self {
     ///
     /// [`ptr::read_volatile`]: crate::ptr::read_volatile()
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
     #[inline]
-    pub unsafe fn read_volatile(self) -> T
+    pub const unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `read_volatile`.
         unsafe { read_volatile(self) }
     }
@@ -907,6 +1195,41 @@ pub unsafe fn read_volatile(self) -> T
         unsafe { read_unaligned(self) }
     }
 
+    /// Reads the value `byte_offset` bytes past `self` without moving it.
+    ///
+    /// The resulting location may be unaligned. This is a shorthand for
+    /// `self.cast::<u8>().add(byte_offset).cast::<T>().read_unaligned()`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the safety contract of [`add`] for computing
+    /// `self.cast::<u8>().add(byte_offset)`, and that of [`ptr::read_unaligned`]
+    /// for reading from the resulting pointer.
+    ///
+    /// [`add`]: #method.add
+    /// [`ptr::read_unaligned`]: crate::ptr::read_unaligned()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_unaligned_at)]
+    ///
+    /// let mut bytes = [0u8, 0x78, 0x56, 0x34, 0x12, 0, 0, 0];
+    /// let base = bytes.as_mut_ptr() as *mut u32;
+    /// let value = unsafe { base.read_unaligned_at(1) };
+    /// assert_eq!(u32::from_le(value), 0x1234_5678);
+    /// ```
+    #[unstable(feature = "ptr_unaligned_at", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_read", issue = "80377")]
+    #[inline]
+    pub const unsafe fn read_unaligned_at(self, byte_offset: usize) -> T
+    where
+        T: Sized,
+    {
+        // SAFETY: the caller must uphold the safety contract for `add` and `read_unaligned`.
+        unsafe { self.cast::<u8>().add(byte_offset).cast::<T>().read_unaligned() }
+    }
+
     /// Copies `count * size_of<T>` bytes from `self` to `dest`. The source
     /// and destination may overlap.
     ///
@@ -922,6 +1245,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
+        debug_assert_aligned(dest);
         // SAFETY: the caller must uphold the safety contract for `copy`.
         unsafe { copy(self, dest, count) }
     }
@@ -941,6 +1266,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
+        debug_assert_aligned(dest);
         // SAFETY: the caller must uphold the safety contract for `copy_nonoverlapping`.
         unsafe { copy_nonoverlapping(self, dest, count) }
     }
@@ -952,6 +1279,23 @@ pub unsafe fn read_volatile(self) -> T
     ///
     /// See [`ptr::copy`] for safety concerns and examples.
     ///
+    /// # Examples
+    ///
+    /// Shifting the start of a buffer to the right, with overlapping source and destination:
+    ///
+    /// ```
+    /// let mut buf = [1, 2, 3, 4, 0];
+    /// let start = buf.as_mut_ptr();
+    ///
+    /// unsafe {
+    ///     // Make room for a new first element.
+    ///     start.add(1).copy_from(start, 4);
+    ///     start.write(0);
+    /// }
+    ///
+    /// assert_eq!(buf, [0, 1, 2, 3, 4]);
+    /// ```
+    ///
     /// [`ptr::copy`]: crate::ptr::copy()
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
     #[stable(feature = "pointer_methods", since = "1.26.0")]
@@ -960,6 +1304,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(src);
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `copy`.
         unsafe { copy(src, self, count) }
     }
@@ -971,6 +1317,22 @@ pub unsafe fn read_volatile(self) -> T
     ///
     /// See [`ptr::copy_nonoverlapping`] for safety concerns and examples.
     ///
+    /// # Examples
+    ///
+    /// Filling a buffer from a separate source:
+    ///
+    /// ```
+    /// let src = [1, 2, 3];
+    /// let mut dst = [0; 5];
+    ///
+    /// unsafe {
+    ///     // Write the three elements after the first one of `dst`.
+    ///     dst.as_mut_ptr().add(1).copy_from_nonoverlapping(src.as_ptr(), src.len());
+    /// }
+    ///
+    /// assert_eq!(dst, [0, 1, 2, 3, 0]);
+    /// ```
+    ///
     /// [`ptr::copy_nonoverlapping`]: crate::ptr::copy_nonoverlapping()
     #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
     #[stable(feature = "pointer_methods", since = "1.26.0")]
@@ -979,6 +1341,8 @@ pub unsafe fn read_volatile(self) -> T
     where
         T: Sized,
     {
+        debug_assert_aligned(src);
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `copy_nonoverlapping`.
         unsafe { copy_nonoverlapping(src, self, count) }
     }
@@ -1008,6 +1372,7 @@ pub unsafe fn drop_in_place(self) {
     where
         T: Sized,
     {
+        debug_assert_aligned(self);
         // SAFETY: the caller must uphold the safety contract for `write`.
         unsafe { write(self, val) }
     }
@@ -1015,12 +1380,30 @@ pub unsafe fn drop_in_place(self) {
     /// Invokes memset on the specified pointer, setting `count * size_of::<T>()`
     /// bytes of memory starting at `self` to `val`.
     ///
+    /// Note that `count` is in units of `T`, not bytes.
+    ///
     /// See [`ptr::write_bytes`] for safety concerns and examples.
     ///
+    /// # Examples
+    ///
+    /// Zeroing a buffer:
+    ///
+    /// ```
+    /// let mut buf = [0xdead_beef_u32; 4];
+    ///
+    /// unsafe {
+    ///     // Four `u32`s, so this writes 16 bytes.
+    ///     buf.as_mut_ptr().write_bytes(0, buf.len());
+    /// }
+    ///
+    /// assert_eq!(buf, [0; 4]);
+    /// ```
+    ///
     /// [`ptr::write_bytes`]: crate::ptr::write_bytes()
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_write", issue = "none")]
     #[inline]
-    pub unsafe fn write_bytes(self, val: u8, count: usize)
+    pub const unsafe fn write_bytes(self, val: u8, count: usize)
     where
         T: Sized,
     {
@@ -1039,8 +1422,9 @@ pub unsafe fn write_bytes(self, val: u8, count: usize)
     ///
     /// [`ptr::write_volatile`]: crate::ptr::write_volatile()
     #[stable(feature = "pointer_methods", since = "1.26.0")]
+    #[rustc_const_unstable(feature = "const_ptr_volatile", issue = "none")]
     #[inline]
-    pub unsafe fn write_volatile(self, val: T)
+    pub const unsafe fn write_volatile(self, val: T)
     where
         T: Sized,
     {
@@ -1067,6 +1451,42 @@ pub unsafe fn write_volatile(self, val: T)
         unsafe { write_unaligned(self, val) }
     }
 
+    /// Overwrites the memory location `byte_offset` bytes past `self` with the
+    /// given value, without reading or dropping the old value.
+    ///
+    /// The resulting location may be unaligned. This is a shorthand for
+    /// `self.cast::<u8>().add(byte_offset).cast::<T>().write_unaligned(val)`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must uphold the safety contract of [`add`] for computing
+    /// `self.cast::<u8>().add(byte_offset)`, and that of [`ptr::write_unaligned`]
+    /// for writing to the resulting pointer.
+    ///
+    /// [`add`]: #method.add
+    /// [`ptr::write_unaligned`]: crate::ptr::write_unaligned()
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(ptr_unaligned_at)]
+    ///
+    /// let mut bytes = [0u8; 8];
+    /// let base = bytes.as_mut_ptr() as *mut u32;
+    /// unsafe { base.write_unaligned_at(3, 0x1234_5678u32.to_le()) };
+    /// assert_eq!(bytes, [0, 0, 0, 0x78, 0x56, 0x34, 0x12, 0]);
+    /// ```
+    #[unstable(feature = "ptr_unaligned_at", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_write", issue = "none")]
+    #[inline]
+    pub const unsafe fn write_unaligned_at(self, byte_offset: usize, val: T)
+    where
+        T: Sized,
+    {
+        // SAFETY: the caller must uphold the safety contract for `add` and `write_unaligned`.
+        unsafe { self.cast::<u8>().add(byte_offset).cast::<T>().write_unaligned(val) }
+    }
+
     /// Replaces the value at `self` with `src`, returning the old
     /// value, without dropping either.
     ///
@@ -1108,6 +1528,10 @@ pub unsafe fn swap(self, with: *mut T)
     /// return `usize::MAX`. Only your algorithm's performance can depend
     /// on getting a usable offset here, not its correctness.
     ///
+    /// During const evaluation, a usable offset is only returned if the allocation the pointer
+    /// points into is known to be at least as aligned as `align`, such as for pointers into a
+    /// suitably aligned `static`.
+    ///
     /// The offset is expressed in number of `T` elements, and not bytes. The value returned can be
     /// used with the `wrapping_add` method.
     ///
@@ -1140,7 +1564,8 @@ pub unsafe fn swap(self, with: *mut T)
     /// # } }
     /// ```
     #[stable(feature = "align_offset", since = "1.36.0")]
-    pub fn align_offset(self, align: usize) -> usize
+    #[rustc_const_unstable(feature = "const_align_offset", issue = "none")]
+    pub const fn align_offset(self, align: usize) -> usize
     where
         T: Sized,
     {
@@ -1226,6 +1651,81 @@ pub unsafe fn get_unchecked_mut<I>(self, index: I) -> *mut I::Output
         unsafe { index.get_unchecked_mut(self) }
     }
 
+    /// Splits the raw slice into a prefix, a middle raw slice of aligned `U`s, and a suffix,
+    /// like [`slice::align_to_mut`] does for slices.
+    ///
+    /// See [`<*const [T]>::align_to`] for details.
+    ///
+    /// # Safety
+    ///
+    /// See [`<*const [T]>::align_to`].
+    ///
+    /// [`slice::align_to_mut`]: ../../std/primitive.slice.html#method.align_to_mut
+    /// [`<*const [T]>::align_to`]: ../../std/primitive.pointer.html#method.align_to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_ptr_align_to, slice_ptr_len)]
+    ///
+    /// let mut bytes = [0u8; 11];
+    /// let raw = &mut bytes[..] as *mut [u8];
+    /// let (prefix, middle, suffix) = unsafe { raw.align_to::<u32>() };
+    /// assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), 11);
+    /// assert_eq!(middle as *mut u32 as usize % 4, 0);
+    /// ```
+    #[unstable(feature = "slice_ptr_align_to", issue = "none")]
+    #[rustc_const_unstable(feature = "const_slice_ptr_align_to", issue = "none")]
+    #[inline]
+    pub const unsafe fn align_to<U>(self) -> (*mut [T], *mut [U], *mut [T]) {
+        // SAFETY: the caller must uphold the safety contract for `align_to`.
+        let (prefix, middle, suffix) = unsafe { (self as *const [T]).align_to::<U>() };
+        (prefix as *mut [T], middle as *mut [U], suffix as *mut [T])
+    }
+
+    /// Copies the elements in `src` to the part of the raw slice starting at `dest`. The two
+    /// ranges may overlap.
+    ///
+    /// This is the raw slice counterpart of [`slice::copy_within`], for memory that may not be
+    /// initialized or that no reference may be created to. The elements are copied bitwise, like
+    /// [`ptr::copy`] does.
+    ///
+    /// # Safety
+    ///
+    /// Both `src` and `dest..dest + src.len()` must lie within `0..self.len()`, and `self`
+    /// must be [valid] for reads and writes of those elements. See [`ptr::copy`] for details.
+    ///
+    /// Out-of-bounds ranges are caught by debug assertions.
+    ///
+    /// [`slice::copy_within`]: ../../std/primitive.slice.html#method.copy_within
+    /// [`ptr::copy`]: crate::ptr::copy()
+    /// [valid]: crate::ptr#safety
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_ptr_copy_within)]
+    ///
+    /// let mut buf = [1, 2, 3, 4, 5];
+    /// let raw = &mut buf[..] as *mut [i32];
+    /// unsafe { raw.copy_within(1..4, 0) };
+    /// assert_eq!(buf, [2, 3, 4, 4, 5]);
+    /// ```
+    #[unstable(feature = "slice_ptr_copy_within", issue = "none")]
+    #[rustc_const_unstable(feature = "const_intrinsic_copy", issue = "80697")]
+    #[inline]
+    pub const unsafe fn copy_within(self, src: Range<usize>, dest: usize) {
+        let len = self.len();
+        debug_assert!(src.start <= src.end, "source range starts after it ends");
+        debug_assert!(src.end <= len, "source range out of bounds");
+        let count = src.end - src.start;
+        debug_assert!(dest <= len - count, "destination out of bounds");
+        let ptr = self.as_mut_ptr();
+        // SAFETY: the caller guarantees that both ranges lie within `self`, and must uphold the
+        // safety contract for `copy`.
+        unsafe { copy(ptr.add(src.start), ptr.add(dest), count) }
+    }
+
     /// Returns `None` if the pointer is null, or else returns a shared slice to
     /// the value wrapped in `Some`. In contrast to [`as_ref`], this does not require
     /// that the value has to be initialized.
@@ -1329,6 +1829,52 @@ pub unsafe fn as_uninit_slice_mut<'a>(self) -> Option<&'a mut [MaybeUninit<T>]>
     }
 }
 
+#[lang = "mut_str_ptr"]
+impl *mut str {
+    /// Returns the length of a raw string slice.
+    ///
+    /// The returned value is the number of **bytes**, not the number of characters.
+    ///
+    /// This function is safe, even when the raw string slice cannot be cast to a `&mut str`
+    /// because the pointer is null or the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(str_ptr_len)]
+    ///
+    /// let mut s = String::from("héllo");
+    /// let ptr = s.as_mut_str() as *mut str;
+    /// assert_eq!(ptr.len(), 6);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_ptr_len", issue = "none")]
+    #[rustc_const_unstable(feature = "str_ptr_len", issue = "none")]
+    pub const fn len(self) -> usize {
+        metadata(self)
+    }
+
+    /// Returns a raw pointer to the string slice's buffer.
+    ///
+    /// This is equivalent to casting `self` to `*mut u8`, but more type-safe.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(str_ptr_len)]
+    ///
+    /// let mut s = String::from("hello");
+    /// let ptr = s.as_mut_str() as *mut str;
+    /// assert_eq!(ptr.as_mut_ptr(), s.as_mut_ptr());
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_ptr_len", issue = "none")]
+    #[rustc_const_unstable(feature = "str_ptr_len", issue = "none")]
+    pub const fn as_mut_ptr(self) -> *mut u8 {
+        self as *mut u8
+    }
+}
+
 // Equality for pointers
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: ?Sized> PartialEq for *mut T {
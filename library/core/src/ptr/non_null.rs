@@ -4,6 +4,7 @@
 use crate::hash;
 use crate::marker::Unsize;
 use crate::mem::{self, MaybeUninit};
+use crate::num::NonZeroUsize;
 use crate::ops::{CoerceUnsized, DispatchFromDyn};
 use crate::ptr::Unique;
 use crate::slice::{self, SliceIndex};
@@ -212,6 +213,226 @@ pub const fn as_ptr(self) -> *mut T {
         self.pointer as *mut T
     }
 
+    /// Gets the address portion of the pointer, which is never zero.
+    ///
+    /// See [`pointer::addr`] for more details.
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn addr(self) -> NonZeroUsize {
+        // SAFETY: the pointer is guaranteed by the type to be non-null,
+        // meaning that the address will be non-zero.
+        unsafe { NonZeroUsize::new_unchecked(self.as_ptr().addr()) }
+    }
+
+    /// Creates a new pointer with the given address and the provenance of `self`.
+    ///
+    /// See [`pointer::with_addr`] for more details.
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn with_addr(self, addr: NonZeroUsize) -> Self {
+        // SAFETY: the result of `with_addr` has the address `addr`, which is non-zero.
+        unsafe { NonNull::new_unchecked(self.as_ptr().with_addr(addr.get())) }
+    }
+
+    /// Creates a new pointer by mapping the address of `self` to a new, non-zero one,
+    /// keeping the provenance of `self`.
+    ///
+    /// See [`pointer::map_addr`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    /// use std::num::NonZeroUsize;
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut x = 7u32;
+    /// let ptr = NonNull::from(&mut x);
+    /// let tagged = ptr.map_addr(|addr| NonZeroUsize::new(addr.get() | 1).unwrap());
+    /// let untagged = tagged.map_addr(|addr| NonZeroUsize::new(addr.get() & !1).unwrap());
+    /// assert_eq!(unsafe { *untagged.as_ptr() }, 7);
+    /// ```
+    #[unstable(feature = "strict_provenance", issue = "none")]
+    #[inline]
+    pub fn map_addr(self, f: impl FnOnce(NonZeroUsize) -> NonZeroUsize) -> Self {
+        self.with_addr(f(self.addr()))
+    }
+
+    /// Calculates the offset from a pointer in bytes.
+    ///
+    /// `count` is in units of **bytes**. This is a convenience for casting to a `u8` pointer and
+    /// using [`offset`](pointer::offset) on it; the metadata of unsized types is kept.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`offset`](pointer::offset) apply, in bytes. In particular, the
+    /// result stays in bounds of the same allocated object, so it cannot be null.
+    #[unstable(feature = "nonnull_byte_offsets", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "71499")]
+    #[inline]
+    pub const unsafe fn byte_offset(self, count: isize) -> Self {
+        let (addr, metadata) = self.to_raw_parts();
+        // SAFETY: the caller must uphold the safety contract for `offset`, which keeps the
+        // result in bounds of the allocated object `self` points into, and so non-null.
+        unsafe {
+            let addr = addr.as_ptr().cast::<u8>().offset(count).cast::<()>();
+            NonNull::from_raw_parts(NonNull::new_unchecked(addr), metadata)
+        }
+    }
+
+    /// Calculates the offset from a pointer in bytes (convenience for
+    /// `.byte_offset(count as isize)`).
+    ///
+    /// `count` is in units of **bytes**; the metadata of unsized types is kept.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`add`](pointer::add) apply, in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(nonnull_byte_offsets)]
+    /// use std::ptr::NonNull;
+    ///
+    /// #[repr(C)]
+    /// struct Block {
+    ///     len: u32,
+    ///     data: [u8; 4],
+    /// }
+    ///
+    /// let mut block = Block { len: 4, data: *b"rust" };
+    /// let header = NonNull::from(&mut block).cast::<u32>();
+    /// let payload = unsafe { header.byte_add(4).cast::<[u8; 4]>() };
+    /// assert_eq!(unsafe { payload.as_ref() }, b"rust");
+    /// ```
+    #[unstable(feature = "nonnull_byte_offsets", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "71499")]
+    #[inline]
+    pub const unsafe fn byte_add(self, count: usize) -> Self {
+        let (addr, metadata) = self.to_raw_parts();
+        // SAFETY: the caller must uphold the safety contract for `add`, which keeps the
+        // result in bounds of the allocated object `self` points into, and so non-null.
+        unsafe {
+            let addr = addr.as_ptr().cast::<u8>().add(count).cast::<()>();
+            NonNull::from_raw_parts(NonNull::new_unchecked(addr), metadata)
+        }
+    }
+
+    /// Calculates the offset from a pointer in bytes (convenience for
+    /// `.byte_offset((count as isize).wrapping_neg())`).
+    ///
+    /// `count` is in units of **bytes**; the metadata of unsized types is kept.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`sub`](pointer::sub) apply, in bytes.
+    #[unstable(feature = "nonnull_byte_offsets", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset", issue = "71499")]
+    #[inline]
+    pub const unsafe fn byte_sub(self, count: usize) -> Self {
+        let (addr, metadata) = self.to_raw_parts();
+        // SAFETY: the caller must uphold the safety contract for `sub`, which keeps the
+        // result in bounds of the allocated object `self` points into, and so non-null.
+        unsafe {
+            let addr = addr.as_ptr().cast::<u8>().sub(count).cast::<()>();
+            NonNull::from_raw_parts(NonNull::new_unchecked(addr), metadata)
+        }
+    }
+
+    /// Calculates the distance between two pointers in bytes.
+    ///
+    /// This is a convenience for casting both pointers to `u8` pointers and using
+    /// [`offset_from`](pointer::offset_from) on them. The metadata of unsized types is ignored,
+    /// and `origin` may point to a different type than `self`.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`offset_from`](pointer::offset_from) apply, in bytes.
+    #[unstable(feature = "nonnull_byte_offsets", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset_from", issue = "41079")]
+    #[inline]
+    pub const unsafe fn byte_offset_from<U: ?Sized>(self, origin: NonNull<U>) -> isize {
+        // SAFETY: the caller must uphold the safety contract for `offset_from`.
+        unsafe { self.as_ptr().cast::<u8>().offset_from(origin.as_ptr().cast::<u8>()) }
+    }
+
+    /// Calculates the distance between two pointers, in units of `T`.
+    ///
+    /// This is a convenience for [`offset_from`](pointer::offset_from) on the raw pointers.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`offset_from`](pointer::offset_from) apply.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `T` is a Zero-Sized Type ("ZST").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(nonnull_offset_from)]
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut a = [0; 5];
+    /// let ptr1 = NonNull::from(&mut a[1]);
+    /// let ptr2 = NonNull::from(&mut a[3]);
+    /// unsafe {
+    ///     assert_eq!(ptr2.offset_from(ptr1), 2);
+    ///     assert_eq!(ptr1.offset_from(ptr2), -2);
+    /// }
+    /// ```
+    #[unstable(feature = "nonnull_offset_from", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset_from", issue = "41079")]
+    #[inline]
+    pub const unsafe fn offset_from(self, origin: NonNull<T>) -> isize
+    where
+        T: Sized,
+    {
+        // SAFETY: the caller must uphold the safety contract for `offset_from`.
+        unsafe { self.as_ptr().offset_from(origin.as_ptr()) }
+    }
+
+    /// Calculates the distance between two pointers, in units of `T`, where `self` is known to
+    /// be at or after `origin`.
+    ///
+    /// This is [`offset_from`](NonNull::offset_from) with the result known to be non-negative,
+    /// so that it can be returned as a `usize`.
+    ///
+    /// # Safety
+    ///
+    /// The same rules as for [`offset_from`](pointer::offset_from) apply, and in addition
+    /// `self` must not be before `origin`. This is checked in debug builds.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `T` is a Zero-Sized Type ("ZST").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(nonnull_offset_from)]
+    /// use std::ptr::NonNull;
+    ///
+    /// let mut a = [0; 5];
+    /// let start = NonNull::from(&mut a[0]);
+    /// let end = NonNull::from(&mut a[4]);
+    /// assert_eq!(unsafe { end.sub_ptr(start) }, 4);
+    /// ```
+    #[unstable(feature = "nonnull_offset_from", issue = "none")]
+    #[rustc_const_unstable(feature = "const_ptr_offset_from", issue = "41079")]
+    #[inline]
+    pub const unsafe fn sub_ptr(self, origin: NonNull<T>) -> usize
+    where
+        T: Sized,
+    {
+        // SAFETY: the caller must uphold the safety contract for `offset_from`.
+        let distance = unsafe { self.offset_from(origin) };
+        debug_assert!(distance >= 0, "`sub_ptr` called with `origin` after `self`");
+        distance as usize
+    }
+
     /// Returns a shared reference to the value. If the value may be uninitialized, [`as_uninit_ref`]
     /// must be used instead.
     ///
@@ -389,6 +610,9 @@ pub const fn as_mut_ptr(self) -> *mut T {
     /// Returns a shared reference to a slice of possibly uninitialized values. In contrast to
     /// [`as_ref`], this does not require that the value has to be initialized.
     ///
+    /// Unlike the raw slice pointer version, this returns the slice directly rather than an
+    /// `Option`, since a `NonNull` is never null.
+    ///
     /// For the mutable counterpart see [`as_uninit_slice_mut`].
     ///
     /// [`as_ref`]: NonNull::as_ref
@@ -425,7 +649,7 @@ pub const fn as_mut_ptr(self) -> *mut T {
     /// [valid]: crate::ptr#safety
     #[inline]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
-    pub unsafe fn as_uninit_slice<'a>(&self) -> &'a [MaybeUninit<T>] {
+    pub unsafe fn as_uninit_slice<'a>(self) -> &'a [MaybeUninit<T>] {
         // SAFETY: the caller must uphold the safety contract for `as_uninit_slice`.
         unsafe { slice::from_raw_parts(self.cast().as_ptr(), self.len()) }
     }
@@ -433,6 +657,9 @@ pub unsafe fn as_uninit_slice<'a>(&self) -> &'a [MaybeUninit<T>] {
     /// Returns a unique reference to a slice of possibly uninitialized values. In contrast to
     /// [`as_mut`], this does not require that the value has to be initialized.
     ///
+    /// Unlike the raw slice pointer version, this returns the slice directly rather than an
+    /// `Option`, since a `NonNull` is never null.
+    ///
     /// For the shared counterpart see [`as_uninit_slice`].
     ///
     /// [`as_mut`]: NonNull::as_mut
@@ -486,7 +713,7 @@ pub unsafe fn as_uninit_slice<'a>(&self) -> &'a [MaybeUninit<T>] {
     /// ```
     #[inline]
     #[unstable(feature = "ptr_as_uninit", issue = "75402")]
-    pub unsafe fn as_uninit_slice_mut<'a>(&self) -> &'a mut [MaybeUninit<T>] {
+    pub unsafe fn as_uninit_slice_mut<'a>(self) -> &'a mut [MaybeUninit<T>] {
         // SAFETY: the caller must uphold the safety contract for `as_uninit_slice_mut`.
         unsafe { slice::from_raw_parts_mut(self.cast().as_ptr(), self.len()) }
     }
@@ -3157,8 +3157,9 @@ pub fn swap_with_slice(&mut self, other: &mut [T]) {
         }
     }
 
-    /// Function to calculate lengths of the middle and trailing slice for `align_to{,_mut}`.
-    fn align_to_offsets<U>(&self) -> (usize, usize) {
+    /// Function to calculate lengths of the middle and trailing slice for `align_to{,_mut}`,
+    /// given the length `len` of the already aligned rest of the slice.
+    pub(crate) const fn align_to_offsets<U>(len: usize) -> (usize, usize) {
         // What we gonna do about `rest` is figure out what multiple of `U`s we can put in a
         // lowest number of `T`s. And how many `T`s we need for each such "multiple".
         //
@@ -3178,11 +3179,9 @@ fn align_to_offsets<U>(&self) -> (usize, usize) {
         //
         // Luckily since all this is constant-evaluated... performance here matters not!
         #[inline]
-        fn gcd(a: usize, b: usize) -> usize {
+        const fn gcd(a: usize, b: usize) -> usize {
             use crate::intrinsics;
             // iterative stein’s algorithm
-            // We should still make this `const fn` (and revert to recursive algorithm if we do)
-            // because relying on llvm to consteval all this is… well, it makes me uncomfortable.
 
             // SAFETY: `a` and `b` are checked to be non-zero values.
             let (ctz_a, mut ctz_b) = unsafe {
@@ -3194,14 +3193,16 @@ fn gcd(a: usize, b: usize) -> usize {
                 }
                 (intrinsics::cttz_nonzero(a), intrinsics::cttz_nonzero(b))
             };
-            let k = ctz_a.min(ctz_b);
+            let k = if ctz_a < ctz_b { ctz_a } else { ctz_b };
             let mut a = a >> ctz_a;
             let mut b = b;
             loop {
                 // remove all factors of 2 from b
                 b >>= ctz_b;
                 if a > b {
-                    mem::swap(&mut a, &mut b);
+                    let t = a;
+                    a = b;
+                    b = t;
                 }
                 b = b - a;
                 // SAFETY: `b` is checked to be non-zero.
@@ -3219,9 +3220,9 @@ fn gcd(a: usize, b: usize) -> usize {
         let us: usize = mem::size_of::<T>() / gcd;
 
         // Armed with this knowledge, we can find how many `U`s we can fit!
-        let us_len = self.len() / ts * us;
+        let us_len = len / ts * us;
         // And how many `T`s will be in the trailing slice!
-        let ts_len = self.len() % ts;
+        let ts_len = len % ts;
         (us_len, ts_len)
     }
 
@@ -3272,7 +3273,7 @@ pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
             (self, &[], &[])
         } else {
             let (left, rest) = self.split_at(offset);
-            let (us_len, ts_len) = rest.align_to_offsets::<U>();
+            let (us_len, ts_len) = Self::align_to_offsets::<U>(rest.len());
             // SAFETY: now `rest` is definitely aligned, so `from_raw_parts` below is okay,
             // since the caller guarantees that we can transmute `T` to `U` safely.
             unsafe {
@@ -3338,7 +3339,7 @@ pub unsafe fn align_to_mut<U>(&mut self) -> (&mut [T], &mut [U], &mut [T]) {
             (self, &mut [], &mut [])
         } else {
             let (left, rest) = self.split_at_mut(offset);
-            let (us_len, ts_len) = rest.align_to_offsets::<U>();
+            let (us_len, ts_len) = Self::align_to_offsets::<U>(rest.len());
             let rest_len = rest.len();
             let mut_ptr = rest.as_mut_ptr();
             // We can't use `rest` again after this, that would invalidate its alias `mut_ptr`!
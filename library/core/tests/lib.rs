@@ -10,12 +10,17 @@
 #![feature(cell_update)]
 #![feature(cfg_panic)]
 #![feature(cfg_target_has_atomic)]
+#![feature(const_align_offset)]
 #![feature(const_assume)]
 #![feature(const_cell_into_inner)]
+#![feature(const_maybe_uninit_as_ptr)]
 #![feature(const_maybe_uninit_assume_init)]
 #![feature(const_ptr_read)]
 #![feature(const_ptr_write)]
+#![feature(const_raw_ptr_comparison)]
+#![feature(const_ptr_as_ref)]
 #![feature(const_ptr_offset)]
+#![feature(const_ptr_offset_from)]
 #![feature(control_flow_enum)]
 #![feature(core_intrinsics)]
 #![feature(core_private_bignum)]
@@ -58,12 +63,33 @@
 #![feature(const_mut_refs)]
 #![feature(const_pin)]
 #![feature(const_slice_from_raw_parts)]
+#![feature(const_slice_ptr_align_to)]
+#![feature(const_slice_ptr_len)]
 #![feature(const_raw_ptr_deref)]
 #![feature(never_type)]
 #![feature(unwrap_infallible)]
 #![feature(option_result_unwrap_unchecked)]
 #![feature(result_into_ok_or_err)]
 #![feature(ptr_metadata)]
+#![feature(ptr_from_ref)]
+#![feature(ptr_as_non_null)]
+#![feature(ptr_checked_add)]
+#![feature(ptr_same_allocation)]
+#![feature(ptr_addr_eq)]
+#![feature(ptr_cast_aligned)]
+#![feature(ptr_as_uninit)]
+#![feature(ptr_addr_of_first)]
+#![feature(ptr_null_with_metadata)]
+#![feature(ptr_unaligned_at)]
+#![feature(nonnull_slice_from_raw_parts)]
+#![feature(nonnull_byte_offsets)]
+#![feature(nonnull_offset_from)]
+#![feature(str_ptr_len)]
+#![feature(slice_ptr_align_to)]
+#![feature(slice_ptr_copy_within)]
+#![feature(slice_ptr_get)]
+#![feature(slice_ptr_len)]
+#![feature(strict_provenance)]
 #![feature(once_cell)]
 #![feature(unsized_tuple_coercion)]
 #![feature(const_option)]
@@ -336,6 +336,40 @@ fn align_offset_stride1() {
     }
 }
 
+#[test]
+fn align_offset_stride1_matches_strided() {
+    // The stride = 1 case is computed in closed form, while larger strides go through the
+    // general modular-inverse path. For a pointer already aligned to the stride, both must agree
+    // on the number of bytes needed to reach the requested alignment.
+    fn check<T>(align: usize) {
+        let stride = core::mem::size_of::<T>();
+        let mut ptr = stride;
+        while ptr < 4 * align {
+            let bytes = (ptr as *const u8).align_offset(align);
+            let elems = (ptr as *const T).align_offset(align);
+            assert_eq!(
+                bytes,
+                elems * stride,
+                "ptr = {}, align = {}, size = {}",
+                ptr,
+                align,
+                stride
+            );
+            ptr += stride;
+        }
+    }
+
+    let mut align = 1;
+    let limit = if cfg!(miri) { 32 } else { 1024 };
+    while align < limit {
+        check::<u16>(align);
+        check::<u32>(align);
+        check::<u64>(align);
+        check::<[u8; 8]>(align);
+        align = (align + 1).next_power_of_two();
+    }
+}
+
 #[test]
 fn align_offset_weird_strides() {
     #[repr(packed)]
@@ -403,6 +437,37 @@ unsafe fn test_weird_stride<T>(ptr: *const T, align: usize) -> bool {
     assert!(!x);
 }
 
+#[test]
+fn align_offset_const() {
+    #[repr(align(16))]
+    struct Aligned([u8; 32]);
+
+    static X: [u64; 4] = [0; 4];
+    static Y: Aligned = Aligned([0; 32]);
+
+    // The alignment of a `static` is known during const evaluation, so pointers into it can be
+    // aligned up to that alignment.
+    static X_BASE: usize = X.as_ptr().align_offset(8);
+    static X_BYTES: usize = (X.as_ptr() as *const u8).wrapping_add(3).align_offset(8);
+    static Y_BYTES: usize = Y.0.as_ptr().wrapping_add(4).align_offset(16);
+    static Y_WORDS: usize = (Y.0.as_ptr() as *const u32).wrapping_add(1).align_offset(16);
+    // Beyond that, the address is unknown.
+    static X_OVERALIGNED: usize = X.as_ptr().align_offset(16);
+    // Integer addresses are known exactly.
+    const DANGLING: usize = NonNull::<u32>::dangling().as_ptr().align_offset(4);
+    const MISALIGNED: usize = (2 as *const u32).align_offset(8);
+    const ZST: usize = (4 as *const ()).align_offset(8);
+
+    assert_eq!(X_BASE, 0);
+    assert_eq!(X_BYTES, 5);
+    assert_eq!(Y_BYTES, 12);
+    assert_eq!(Y_WORDS, 3);
+    assert_eq!(X_OVERALIGNED, usize::MAX);
+    assert_eq!(DANGLING, 0);
+    assert_eq!(MISALIGNED, usize::MAX);
+    assert_eq!(ZST, usize::MAX);
+}
+
 #[test]
 fn offset_from() {
     let mut a = [0; 5];
@@ -660,3 +725,508 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[test]
+fn with_addr() {
+    let mut data = [1u32, 2, 3];
+    let ptr = data.as_mut_ptr();
+    let last = ptr.with_addr(ptr.addr() + 2 * core::mem::size_of::<u32>());
+    assert_eq!(unsafe { *last }, 3);
+
+    // Fat pointers keep their metadata.
+    let slice: *const [u32] = &data[..2];
+    let moved = slice.with_addr(slice.addr() + core::mem::size_of::<u32>());
+    assert_eq!(unsafe { &*moved }, &[2, 3]);
+}
+
+#[test]
+fn with_exposed_provenance() {
+    let mut data = [1u32, 2, 3, 4];
+
+    // Expose the provenance, do some integer math, and turn the result back into a pointer.
+    // The round-trip is only allowed to access `data` because its provenance was exposed.
+    let addr = data.as_mut_ptr().expose_addr();
+    let third = addr + 2 * core::mem::size_of::<u32>();
+    let ptr: *mut u32 = ptr::with_exposed_provenance_mut(third);
+    unsafe { *ptr += 10 };
+    assert_eq!(data, [1, 2, 13, 4]);
+
+    let ptr: *const u32 = ptr::with_exposed_provenance(third - core::mem::size_of::<u32>());
+    assert_eq!(unsafe { *ptr }, 2);
+}
+
+#[test]
+fn nonnull_tagged_ptr() {
+    use core::num::NonZeroUsize;
+
+    let mut x = 7u32;
+    let ptr = NonNull::from(&mut x);
+    // `u32` is aligned to at least 2 bytes, leaving the lowest bit free for a tag.
+    let tagged = ptr.map_addr(|addr| NonZeroUsize::new(addr.get() | 1).unwrap());
+    assert_eq!(tagged.addr().get() & 1, 1);
+
+    let untagged = tagged.map_addr(|addr| NonZeroUsize::new(addr.get() & !1).unwrap());
+    assert_eq!(untagged, ptr);
+    assert_eq!(unsafe { *untagged.as_ptr() }, 7);
+
+    let same = ptr.with_addr(ptr.addr());
+    assert_eq!(same, ptr);
+}
+
+#[test]
+fn nonnull_byte_offsets() {
+    #[repr(C)]
+    struct Header {
+        len: u16,
+        tag: u16,
+    }
+
+    #[repr(C)]
+    struct Block {
+        header: Header,
+        payload: [u32; 2],
+    }
+
+    let mut block = Block { header: Header { len: 2, tag: 7 }, payload: [10, 20] };
+    let header = NonNull::from(&mut block).cast::<Header>();
+
+    unsafe {
+        let payload = header.byte_add(core::mem::size_of::<Header>()).cast::<u32>();
+        assert_eq!(*payload.as_ptr(), 10);
+        assert_eq!(payload.byte_offset_from(header), 4);
+
+        let second = payload.byte_offset(4);
+        assert_eq!(*second.as_ptr(), 20);
+        assert_eq!(second.byte_sub(8).cast::<Header>().as_ref().tag, 7);
+
+        // Wide pointers keep their metadata.
+        let words = NonNull::from(&mut block.payload[..]);
+        let tail = words.byte_add(4);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(*tail.cast::<u32>().as_ptr(), 20);
+    }
+
+    const OFFSET: isize = {
+        let data = [0u32; 4];
+        unsafe {
+            let first = NonNull::new_unchecked(&data as *const [u32; 4] as *mut u32);
+            first.byte_add(8).byte_offset_from(first)
+        }
+    };
+    assert_eq!(OFFSET, 8);
+}
+
+#[test]
+fn from_ref_keeps_metadata() {
+    let mut data = [1u8, 2, 3];
+
+    let p = ptr::from_ref(&data[1..]);
+    assert_eq!(metadata(p), 2);
+    assert_eq!(unsafe { &*p }, &[2, 3]);
+
+    let p = ptr::from_mut(&mut data[..]);
+    assert_eq!(metadata(p), 3);
+    unsafe { (*p)[0] = 4 };
+    assert_eq!(data, [4, 2, 3]);
+
+    let value = 5u32;
+    let dyn_ref: &dyn Debug = &value;
+    let p = ptr::from_ref(dyn_ref);
+    assert_eq!(metadata(p), metadata(dyn_ref as *const dyn Debug));
+    assert_eq!(metadata(p).size_of(), 4);
+}
+
+#[test]
+fn as_non_null() {
+    let mut x = 5u8;
+    let ptr = &mut x as *mut u8;
+    assert_eq!(ptr.as_non_null(), Some(NonNull::from(&mut x)));
+    assert_eq!((ptr as *const u8).as_non_null(), NonNull::new(ptr));
+
+    assert_eq!(ptr::null::<u8>().as_non_null(), None);
+    assert_eq!(ptr::null_mut::<u8>().as_non_null(), None);
+
+    // Fat pointers only look at their data address.
+    let slice: *mut [u8] = ptr::slice_from_raw_parts_mut(ptr::null_mut(), 3);
+    assert!(slice.as_non_null().is_none());
+
+    const NULL: Option<NonNull<u32>> = ptr::null::<u32>().as_non_null();
+    const NOT_NULL: bool = (&3u32 as *const u32).as_non_null().is_some();
+    assert!(NULL.is_none());
+    assert!(NOT_NULL);
+}
+
+#[test]
+fn checked_add_sub() {
+    let top = usize::MAX as *const u8;
+    assert_eq!(top.checked_add(0), Some(top));
+    assert_eq!(top.checked_add(1), None);
+    assert_eq!(top.checked_sub(usize::MAX), Some(ptr::null()));
+
+    let low = 8 as *mut u32;
+    assert_eq!(low.checked_sub(2), Some(0 as *mut u32));
+    assert_eq!(low.checked_sub(3), None);
+    // The byte offset overflows even though the element count does not.
+    assert_eq!(low.checked_add(usize::MAX / 2), None);
+
+    // Zero-sized types never move.
+    let zst = usize::MAX as *const ();
+    assert_eq!(zst.checked_add(usize::MAX), Some(zst));
+    assert_eq!(zst.checked_sub(usize::MAX), Some(zst));
+}
+
+#[test]
+fn same_allocation() {
+    let data = [1u32, 2, 3];
+    assert!(ptr::same_allocation(&data[0], &data[2]));
+    assert!(ptr::same_allocation(&data[0], data.as_ptr().wrapping_add(3)));
+
+    let a = Box::new(1u32);
+    let b = Box::new(2u32);
+    // Only Miri tracks provenance; compiled code conservatively says "maybe".
+    assert_eq!(ptr::same_allocation(&*a, &*b), !cfg!(miri));
+}
+
+#[test]
+fn addr_eq() {
+    trait Trait {}
+    impl Trait for u32 {}
+    impl Trait for [u32; 2] {}
+
+    let pair = [1u32, 2];
+    let concrete: &u32 = &pair[0];
+    let dynamic: &dyn Trait = &pair;
+
+    // Same data address, different vtables and types.
+    assert!(ptr::addr_eq(concrete, dynamic));
+    assert!(!ptr::eq::<dyn Trait>(concrete, dynamic));
+
+    // Slices with the same start but different lengths.
+    assert!(ptr::addr_eq(&pair[..1], &pair[..]));
+    assert!(!ptr::addr_eq(&pair[..1], &pair[1..]));
+
+    let other = 1u32;
+    assert!(!ptr::addr_eq(&other, dynamic));
+}
+
+#[test]
+fn nonnull_slice_from_raw_parts() {
+    let empty: NonNull<[u32]> = NonNull::slice_from_raw_parts(NonNull::dangling(), 0);
+    assert_eq!(empty.len(), 0);
+    assert_eq!(empty.as_non_null_ptr(), NonNull::dangling());
+
+    let mut data = [1u32, 2, 3];
+    let start = NonNull::from(&mut data[0]);
+    let slice = NonNull::slice_from_raw_parts(start, data.len());
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.as_non_null_ptr(), start);
+    assert_eq!(unsafe { slice.as_ref() }, &[1, 2, 3]);
+}
+
+#[test]
+fn unaligned_at() {
+    let mut bytes = [0xffu8, 0x78, 0x56, 0x34, 0x12, 0xff, 0xff, 0xff];
+    let base = bytes.as_ptr() as *const u32;
+    assert_eq!(u32::from_le(unsafe { base.read_unaligned_at(1) }), 0x1234_5678);
+
+    let base = bytes.as_mut_ptr() as *mut u32;
+    unsafe { base.write_unaligned_at(3, 0xaabb_ccddu32.to_le()) };
+    assert_eq!(bytes, [0xff, 0x78, 0x56, 0xdd, 0xcc, 0xbb, 0xaa, 0xff]);
+    assert_eq!(u32::from_le(unsafe { base.read_unaligned_at(3) }), 0xaabb_ccdd);
+
+    const PARSED: u32 = {
+        let bytes = [0u8, 0x78, 0x56, 0x34, 0x12, 0, 0, 0];
+        u32::from_le(unsafe { (&bytes as *const [u8; 8] as *const u32).read_unaligned_at(1) })
+    };
+    assert_eq!(PARSED, 0x1234_5678);
+}
+
+#[test]
+fn slice_ptr_align_to() {
+    let bytes = [0u8; 32];
+    for start in 0..4 {
+        for end in start..bytes.len() {
+            let raw = &bytes[start..end] as *const [u8];
+            let (prefix, middle, suffix) = unsafe { raw.align_to::<u32>() };
+            let (p, m, s) = unsafe { bytes[start..end].align_to::<u32>() };
+
+            assert_eq!(prefix, p as *const [u8]);
+            assert_eq!(middle, m as *const [u32]);
+            assert_eq!(suffix, s as *const [u8]);
+            assert_eq!(prefix.len() + middle.len() * 4 + suffix.len(), end - start);
+            if middle.len() > 0 {
+                assert_eq!(middle.as_ptr() as usize % 4, 0);
+                assert!(prefix.len() < 4 && suffix.len() < 4);
+            }
+        }
+    }
+
+    // Zero-sized types are never split.
+    let units = [(); 5];
+    let raw = &units[..] as *const [()];
+    let (prefix, middle, suffix) = unsafe { raw.align_to::<u32>() };
+    assert_eq!((prefix.len(), middle.len(), suffix.len()), (5, 0, 0));
+}
+
+#[test]
+fn slice_ptr_align_to_const() {
+    // The interpreter knows how the arrays are aligned, so the splits are exact.
+    const LENS: (usize, usize, usize) = unsafe {
+        let words = [0u32; 4];
+        let bytes = ptr::slice_from_raw_parts((words.as_ptr() as *const u8).add(1), 13);
+        let (prefix, middle, suffix) = bytes.align_to::<u32>();
+        (prefix.len(), middle.len(), suffix.len())
+    };
+    assert_eq!(LENS, (3, 2, 2));
+
+    const MUT_LENS: (usize, usize, usize) = unsafe {
+        let mut halves = [0u16; 3];
+        let bytes = ptr::slice_from_raw_parts_mut(halves.as_mut_ptr() as *mut u8, 6);
+        let (prefix, middle, suffix) = bytes.align_to::<u16>();
+        (prefix.len(), middle.len(), suffix.len())
+    };
+    assert_eq!(MUT_LENS, (0, 3, 0));
+}
+
+#[test]
+fn null_with_metadata() {
+    let p: *const [u8] = ptr::null_with_metadata(3);
+    assert!(p.is_null());
+    assert_eq!(p.len(), 3);
+
+    let p: *mut [u8] = ptr::null_mut_with_metadata(3);
+    assert!(p.is_null());
+    assert_eq!(p.len(), 3);
+
+    let value = 7u32;
+    let vtable = metadata(&value as &dyn Debug);
+    let p: *const dyn Debug = ptr::null_with_metadata(vtable);
+    assert!(p.is_null());
+    assert_eq!(metadata(p).size_of(), 4);
+
+    const EMPTY: *const [u8] = ptr::null_with_metadata(0);
+    assert!(EMPTY.is_null());
+}
+
+#[test]
+#[cfg_attr(feature = "debug_ptr_wrap", should_panic(expected = "wrapped around"))]
+fn wrapping_offset_wraps_around() {
+    let last = usize::MAX as *const u8;
+    assert_eq!(last.wrapping_add(1) as usize, 0);
+    assert_eq!((0 as *mut u16).wrapping_sub(1) as usize, usize::MAX - 1);
+}
+
+#[test]
+fn wrapping_offset_without_wrap() {
+    // Staying within the address space is never reported.
+    let data = [0u32; 4];
+    let start = data.as_ptr();
+    let end = start.wrapping_add(data.len());
+    assert_eq!(end.wrapping_sub(data.len()), start);
+    assert_eq!((usize::MAX as *const u8).wrapping_sub(1) as usize, usize::MAX - 1);
+}
+
+#[test]
+fn addr_of_first() {
+    // Under Miri, this checks that the pointers may reach every element of the slice, which
+    // would not be the case for `&data[0] as *const _`.
+    let data = [1u16, 2, 3, 4];
+    let first = ptr::addr_of_first(&data);
+    let sum: u16 = (0..data.len()).map(|i| unsafe { *first.add(i) }).sum();
+    assert_eq!(sum, 10);
+    assert_eq!(first.wrapping_add(data.len()), data.as_ptr_range().end);
+
+    let mut data = [0u16; 4];
+    let first = ptr::addr_of_first_mut(&mut data);
+    for i in 0..4 {
+        unsafe { first.add(i).write(i as u16 * 2) };
+    }
+    assert_eq!(data, [0, 2, 4, 6]);
+
+    let empty: &[u16] = &[];
+    assert!(!ptr::addr_of_first(empty).is_null());
+}
+
+#[test]
+fn slice_ptr_copy_within() {
+    // Backward move, overlapping.
+    let mut buf = [0u8, 1, 2, 3, 4, 5, 6, 7];
+    let raw = &mut buf[..] as *mut [u8];
+    unsafe { raw.copy_within(2..7, 0) };
+    assert_eq!(buf, [2, 3, 4, 5, 6, 5, 6, 7]);
+
+    // Forward move, overlapping.
+    let mut buf = [0u8, 1, 2, 3, 4, 5, 6, 7];
+    let raw = &mut buf[..] as *mut [u8];
+    unsafe { raw.copy_within(0..5, 3) };
+    assert_eq!(buf, [0, 1, 2, 0, 1, 2, 3, 4]);
+
+    // Empty source range, and a move to the very end.
+    let mut buf = [0u16, 1, 2, 3];
+    let raw = &mut buf[..] as *mut [u16];
+    unsafe {
+        raw.copy_within(1..1, 4);
+        raw.copy_within(0..1, 3);
+    }
+    assert_eq!(buf, [0, 1, 2, 0]);
+}
+
+#[test]
+fn write_bytes_const() {
+    const MEMSET: [u32; 4] = {
+        let mut buf = [0xdead_beef_u32; 4];
+        unsafe { (&mut buf as *mut [u32; 4] as *mut u32).add(1).write_bytes(0xab, 2) };
+        buf
+    };
+    assert_eq!(MEMSET, [0xdead_beef, 0xabab_abab, 0xabab_abab, 0xdead_beef]);
+
+    const ZEROED: [u16; 3] = {
+        let mut buf = [7u16; 3];
+        unsafe { ptr::write_bytes(&mut buf as *mut [u16; 3], 0, 1) };
+        buf
+    };
+    assert_eq!(ZEROED, [0; 3]);
+
+    let mut buf = [0xffu8; 4];
+    unsafe { buf.as_mut_ptr().write_bytes(0, 0) };
+    assert_eq!(buf, [0xff; 4]);
+}
+
+#[test]
+fn guaranteed_eq_wide() {
+    const ADDR: *const u8 = 0x100 as *const u8;
+    const DATA: &[u8] = &[1, 2, 3, 4];
+
+    // Integer addresses and lengths are always comparable during const evaluation.
+    const SAME: bool = slice_from_raw_parts(ADDR, 3).guaranteed_eq(slice_from_raw_parts(ADDR, 3));
+    const SAME_NE: bool =
+        slice_from_raw_parts(ADDR, 3).guaranteed_ne(slice_from_raw_parts(ADDR, 3));
+    assert!(SAME);
+    assert!(!SAME_NE);
+
+    // Differing lengths are known to differ even when the addresses cannot be compared.
+    const SHORT: *const [u8] = slice_from_raw_parts(DATA.as_ptr(), 3);
+    const LONG: *const [u8] = slice_from_raw_parts(DATA.as_ptr(), 4);
+    const LEN_EQ: bool = SHORT.guaranteed_eq(LONG);
+    const LEN_NE: bool = SHORT.guaranteed_ne(LONG);
+    assert!(!LEN_EQ);
+    assert!(LEN_NE);
+
+    // At runtime both functions are exact.
+    let a = &DATA[..2] as *const [u8];
+    let b = &DATA[..3] as *const [u8];
+    assert!(a.guaranteed_eq(a));
+    assert!(a.guaranteed_ne(b));
+    assert!(!a.guaranteed_eq(b));
+}
+
+#[test]
+fn str_ptr_len() {
+    let s = "raw strings";
+    let ptr = s as *const str;
+    assert_eq!(ptr.len(), s.len());
+    assert_eq!(ptr.as_ptr(), s.as_ptr());
+
+    // A raw `str` pointer built from parts, without going through a reference.
+    let bytes = b"hello world";
+    let hello = from_raw_parts::<str>(bytes.as_ptr().cast(), 5);
+    assert_eq!(hello.len(), 5);
+    assert_eq!(unsafe { &*hello }, "hello");
+
+    let mut buf = *b"HELLO";
+    let shout = from_raw_parts_mut::<str>(buf.as_mut_ptr().cast(), buf.len());
+    assert_eq!(shout.len(), 5);
+    unsafe { shout.as_mut_ptr().write(b'J') };
+    assert_eq!(&buf, b"JELLO");
+
+    const LEN: usize = ("const" as *const str).len();
+    assert_eq!(LEN, 5);
+}
+
+#[test]
+fn cast_aligned() {
+    let mut words = [1u64, 2, 3];
+    let bytes = words.as_mut_ptr().cast::<u8>();
+    unsafe {
+        let third = bytes.add(16).cast_aligned::<u64>();
+        assert_eq!(*third, 3);
+        let half = bytes.add(12).cast_aligned::<u32>();
+        half.write(7);
+        assert_eq!(half.read(), 7);
+        // Zero-sized types are never misaligned.
+        let _unit = bytes.add(1).cast_aligned::<()>();
+    }
+    assert_eq!(words[0], 1);
+}
+
+#[test]
+fn nonnull_as_uninit_slice() {
+    use core::mem::MaybeUninit;
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 4];
+    let ptr = NonNull::slice_from_raw_parts(NonNull::from(&mut buf).cast::<u8>(), buf.len());
+
+    let view = unsafe { ptr.as_uninit_slice_mut() };
+    assert_eq!(view.len(), 4);
+    for (i, byte) in view.iter_mut().enumerate() {
+        byte.write(i as u8 * 3);
+    }
+
+    let view = unsafe { ptr.as_uninit_slice() };
+    assert_eq!(view.len(), 4);
+    let values: [u8; 4] = [0usize, 1, 2, 3].map(|i| unsafe { view[i].assume_init() });
+    assert_eq!(values, [0, 3, 6, 9]);
+}
+
+#[test]
+fn as_uninit_mut() {
+    use core::mem::MaybeUninit;
+
+    let mut x = MaybeUninit::<u64>::uninit();
+    let ptr = x.as_mut_ptr();
+    let slot = unsafe { ptr.as_uninit_mut() }.unwrap();
+    slot.write(0x1234_5678_9abc_def0);
+    assert_eq!(unsafe { x.assume_init() }, 0x1234_5678_9abc_def0);
+
+    assert!(unsafe { ptr::null_mut::<u64>().as_uninit_mut() }.is_none());
+
+    const WRITTEN: u32 = unsafe {
+        let mut x = MaybeUninit::<u32>::uninit();
+        if let Some(slot) = x.as_mut_ptr().as_uninit_mut() {
+            *slot = MaybeUninit::new(5);
+        }
+        x.assume_init()
+    };
+    assert_eq!(WRITTEN, 5);
+
+    const NULL: bool = unsafe { ptr::null_mut::<u32>().as_uninit_mut().is_none() };
+    assert!(NULL);
+}
+
+#[test]
+fn nonnull_offset_from() {
+    let mut buf = [0u64; 8];
+    let start = NonNull::from(&mut buf).cast::<u64>();
+
+    unsafe {
+        let third = NonNull::new_unchecked(start.as_ptr().add(3));
+        let end = NonNull::new_unchecked(start.as_ptr().add(7));
+
+        assert_eq!(third.offset_from(start), 3);
+        assert_eq!(start.offset_from(third), -3);
+        assert_eq!(end.offset_from(third), 4);
+        assert_eq!(start.offset_from(start), 0);
+
+        assert_eq!(third.sub_ptr(start), 3);
+        assert_eq!(end.sub_ptr(start), 7);
+        assert_eq!(end.sub_ptr(end), 0);
+    }
+
+    const DISTANCE: usize = unsafe {
+        let buf = [0u16; 4];
+        let start = NonNull::new_unchecked(buf.as_ptr() as *mut u16);
+        let end = NonNull::new_unchecked(buf.as_ptr().add(2) as *mut u16);
+        end.sub_ptr(start)
+    };
+    assert_eq!(DISTANCE, 2);
+}
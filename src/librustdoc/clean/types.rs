@@ -1743,18 +1743,18 @@ impl PrimitiveType {
         }
     }
 
-    crate fn impls(&self, tcx: TyCtxt<'_>) -> &'static ArrayVec<DefId, 4> {
+    crate fn impls(&self, tcx: TyCtxt<'_>) -> &'static ArrayVec<DefId, 6> {
         Self::all_impls(tcx).get(self).expect("missing impl for primitive type")
     }
 
-    crate fn all_impls(tcx: TyCtxt<'_>) -> &'static FxHashMap<PrimitiveType, ArrayVec<DefId, 4>> {
-        static CELL: OnceCell<FxHashMap<PrimitiveType, ArrayVec<DefId, 4>>> = OnceCell::new();
+    crate fn all_impls(tcx: TyCtxt<'_>) -> &'static FxHashMap<PrimitiveType, ArrayVec<DefId, 6>> {
+        static CELL: OnceCell<FxHashMap<PrimitiveType, ArrayVec<DefId, 6>>> = OnceCell::new();
 
         CELL.get_or_init(move || {
             use self::PrimitiveType::*;
 
             let single = |a: Option<DefId>| a.into_iter().collect();
-            let both = |a: Option<DefId>, b: Option<DefId>| -> ArrayVec<_, 4> {
+            let both = |a: Option<DefId>, b: Option<DefId>| -> ArrayVec<_, 6> {
                 a.into_iter().chain(b).collect()
             };
 
@@ -1796,6 +1796,8 @@ impl PrimitiveType {
                         .chain(lang_items.mut_ptr_impl())
                         .chain(lang_items.const_slice_ptr_impl())
                         .chain(lang_items.mut_slice_ptr_impl())
+                        .chain(lang_items.const_str_ptr_impl())
+                        .chain(lang_items.mut_str_ptr_impl())
                         .collect()
                 },
                 Reference => ArrayVec::new(),
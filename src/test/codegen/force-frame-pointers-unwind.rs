@@ -0,0 +1,25 @@
+// compile-flags: -C opt-level=0 -C panic=unwind -C force-frame-pointers=unwind
+
+// Test that `-C force-frame-pointers=unwind` keeps frame pointers in functions that may unwind,
+// even leaf ones, and leaves them out of functions that cannot unwind. We disable optimizations
+// above to prevent LLVM from inferring `nounwind`.
+
+#![crate_type = "lib"]
+
+// CHECK: @leaf_that_cannot_unwind(i32 %x) unnamed_addr #0 {
+#[no_mangle]
+pub extern "C" fn leaf_that_cannot_unwind(x: i32) -> i32 {
+    x
+}
+
+// CHECK: @leaf_that_can_unwind(i32 %x) unnamed_addr #1 {
+#[no_mangle]
+pub fn leaf_that_can_unwind(x: i32) -> i32 {
+    x
+}
+
+// CHECK: attributes #0 = {
+// CHECK-NOT: "frame-pointer"
+// CHECK: }
+//
+// CHECK: attributes #1 = { {{.*}}"frame-pointer"="all"{{.*}} }
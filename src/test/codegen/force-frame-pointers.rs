@@ -1,6 +1,38 @@
-// compile-flags: -C no-prepopulate-passes -C force-frame-pointers=y
+// revisions: ALWAYS NON-LEAF LEAF
+// compile-flags: -C no-prepopulate-passes
+//[ALWAYS] compile-flags: -C force-frame-pointers=y
+//[NON-LEAF] compile-flags: -C force-frame-pointers=non-leaf
+//[LEAF] compile-flags: -C force-frame-pointers=leaf
 
 #![crate_type="lib"]
 
-// CHECK: attributes #{{.*}} "frame-pointer"="all"
-pub fn foo() {}
+extern "C" {
+    fn external();
+}
+
+// LEAF-LABEL: define{{.*}}@leaf
+// LEAF-SAME: #[[LEAF_ATTRS:[0-9]+]]
+#[no_mangle]
+pub fn leaf() {}
+
+// LEAF-LABEL: define{{.*}}@caller
+// LEAF-SAME: #[[CALLER_ATTRS:[0-9]+]]
+#[no_mangle]
+pub fn caller() {
+    leaf()
+}
+
+// Foreign and upstream functions are only declared, and have no MIR to look for calls in.
+// LEAF-LABEL: define{{.*}}@calls_out
+// LEAF-SAME: #[[CALLS_OUT_ATTRS:[0-9]+]]
+#[no_mangle]
+pub fn calls_out() {
+    unsafe { external() };
+    std::process::abort();
+}
+
+// ALWAYS: attributes #{{.*}} "frame-pointer"="all"
+// NON-LEAF: attributes #{{.*}} "frame-pointer"="non-leaf"
+// LEAF: attributes #[[LEAF_ATTRS]] = {{.*}}"frame-pointer"="all"
+// LEAF-NOT: attributes #[[CALLER_ATTRS]] = {{.*}}"frame-pointer"
+// LEAF-NOT: attributes #[[CALLS_OUT_ATTRS]] = {{.*}}"frame-pointer"
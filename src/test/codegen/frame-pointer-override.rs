@@ -0,0 +1,28 @@
+// Checks how `-C force-frame-pointers` interacts with the target's frame pointer policy: it can
+// turn frame pointers off on targets that merely prefer them, but not on targets that require
+// them.
+
+// revisions: x86_64-apple-off aarch64-apple-off aarch64-apple-on
+// needs-llvm-components: aarch64 x86
+
+//[x86_64-apple-off] compile-flags: --target x86_64-apple-darwin -C force-frame-pointers=off
+//[aarch64-apple-off] compile-flags: --target aarch64-apple-darwin -C force-frame-pointers=off
+//[aarch64-apple-on] compile-flags: --target aarch64-apple-darwin -C force-frame-pointers=on
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang="sized"]
+trait Sized { }
+#[lang="copy"]
+trait Copy { }
+
+// x86_64-apple-off-NOT: "frame-pointer"
+// aarch64-apple-off: attributes #{{.*}} "frame-pointer"="non-leaf"
+// aarch64-apple-on: attributes #{{.*}} "frame-pointer"="all"
+#[no_mangle]
+pub fn peach(x: u32) -> u32 {
+    x
+}
@@ -0,0 +1,28 @@
+// Checks the frame pointer policy each target uses when `-C force-frame-pointers` is not given.
+
+// revisions: aarch64-apple aarch64-ios x86_64-apple x86_64-linux
+// needs-llvm-components: aarch64 x86
+
+//[aarch64-apple] compile-flags: --target aarch64-apple-darwin
+//[aarch64-ios] compile-flags: --target aarch64-apple-ios
+//[x86_64-apple] compile-flags: --target x86_64-apple-darwin
+//[x86_64-linux] compile-flags: --target x86_64-unknown-linux-gnu
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(no_core, lang_items)]
+#![no_core]
+
+#[lang="sized"]
+trait Sized { }
+#[lang="copy"]
+trait Copy { }
+
+// aarch64-apple: attributes #{{.*}} "frame-pointer"="non-leaf"
+// aarch64-ios: attributes #{{.*}} "frame-pointer"="non-leaf"
+// x86_64-apple: attributes #{{.*}} "frame-pointer"="all"
+// x86_64-linux-NOT: "frame-pointer"
+#[no_mangle]
+pub fn peach(x: u32) -> u32 {
+    x
+}
@@ -0,0 +1,17 @@
+// Checks that enabling a sanitizer keeps frame pointers, which the sanitizer runtimes use to
+// collect stack traces, unless `-C force-frame-pointers` says otherwise.
+//
+// needs-sanitizer-address
+// revisions: ASAN ASAN-OFF
+// compile-flags: -C no-prepopulate-passes
+//[ASAN] compile-flags: -Zsanitizer=address
+//[ASAN-OFF] compile-flags: -Zsanitizer=address -C force-frame-pointers=off
+
+#![crate_type = "lib"]
+
+// ASAN: attributes #{{.*}} "frame-pointer"="all"
+// ASAN-OFF-NOT: "frame-pointer"="all"
+#[no_mangle]
+pub fn peach(x: u32) -> u32 {
+    x
+}
@@ -0,0 +1,24 @@
+// check-pass
+// revisions: sim device
+//[sim] compile-flags: --target aarch64-apple-ios-sim
+//[device] compile-flags: --target aarch64-apple-ios
+// needs-llvm-components: aarch64
+
+#![feature(cfg_target_simulator, no_core, lang_items)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[cfg(target_simulator)]
+pub struct Simulator;
+
+#[cfg(not(target_simulator))]
+pub struct Device;
+
+#[cfg(sim)]
+pub type Expected = Simulator;
+
+#[cfg(device)]
+pub type Expected = Device;
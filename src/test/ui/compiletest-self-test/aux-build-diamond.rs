@@ -0,0 +1,9 @@
+// Checks that auxiliary crates are built after the auxiliary crates they depend on, and that
+// a crate shared by several of them (`diamond-base.rs`) is only built once.
+
+// run-pass
+// aux-crate: diamond_top=diamond-top.rs
+
+fn main() {
+    assert_eq!(diamond_top::TOP, 112);
+}
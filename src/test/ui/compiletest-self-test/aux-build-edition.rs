@@ -0,0 +1,14 @@
+// Checks that `aux-build-edition` overrides the edition an auxiliary crate is built with:
+// the auxiliary crate only compiles as 2018 code, while this test stays on 2015.
+
+// run-pass
+// aux-build: edition_2018_only.rs
+// aux-build-edition: edition_2018_only.rs=2018
+
+extern crate edition_2018_only as aux;
+
+fn main() {
+    // The `async` block comes from the macro definition, so it is parsed as 2018 code.
+    let _future = aux::make_future!(42);
+    let _answer = aux::answer();
+}
@@ -0,0 +1,8 @@
+// Checks that `aux-build-fail` expects the auxiliary crate to be rejected: the auxiliary crate
+// calls a function that does not exist, and its diagnostics are compared against the `.stderr`
+// file next to it. The test itself never links against it.
+
+// check-pass
+// aux-build-fail: undefined_symbol.rs
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Checks that `aux-build-flags` passes extra flags to a single auxiliary crate: the auxiliary
+// crate refuses to compile unless it is built with `-Cpanic=abort`, while this test keeps the
+// default unwinding strategy. The flag itself contains `=`, which must survive parsing.
+
+// check-pass
+// compile-flags: -Cpanic=unwind
+// aux-build: panic_abort_only.rs
+// aux-build-flags: panic_abort_only.rs=-Cpanic=abort
+
+#![feature(cfg_panic)]
+
+#[cfg(not(panic = "unwind"))]
+compile_error!("this test must be built with `-Cpanic=unwind`");
+
+fn main() {}
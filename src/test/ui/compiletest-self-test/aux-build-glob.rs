@@ -0,0 +1,14 @@
+// Checks that `aux-build` expands glob patterns to every matching auxiliary file.
+
+// run-pass
+// aux-build: glob-helpers/*.rs
+
+extern crate glob_helper_a;
+extern crate glob_helper_b;
+extern crate glob_helper_c;
+
+fn main() {
+    assert_eq!(glob_helper_a::NAME, "a");
+    assert_eq!(glob_helper_b::NAME, "b");
+    assert_eq!(glob_helper_c::NAME, "c");
+}
@@ -0,0 +1,19 @@
+// Checks that `aux-build` directives can be specific to a revision: each revision only sees
+// (and only builds) its own auxiliary crate.
+
+// run-pass
+// revisions: first second
+//[first] aux-build: revision_first.rs
+//[second] aux-build: revision_second.rs
+
+#[cfg(first)]
+extern crate revision_first as aux;
+#[cfg(second)]
+extern crate revision_second as aux;
+
+fn main() {
+    #[cfg(first)]
+    assert_eq!(aux::NAME, "first");
+    #[cfg(second)]
+    assert_eq!(aux::NAME, "second");
+}
@@ -0,0 +1,14 @@
+// Checks that two `aux-crate`s built from sources with the same crate name are built under
+// their extern names, so that they neither overwrite each other nor clash as crates.
+
+// run-pass
+// aux-crate: shared_v1=shared-v1/shared.rs
+// aux-crate: shared_v2=shared-v2/shared.rs
+
+use std::any::TypeId;
+
+fn main() {
+    assert_eq!(shared_v1::VERSION, 1);
+    assert_eq!(shared_v2::VERSION, 2);
+    assert_ne!(TypeId::of::<shared_v1::Version>(), TypeId::of::<shared_v2::Version>());
+}
@@ -0,0 +1,24 @@
+// Checks that `aux-proc-macro` builds the auxiliary crate as a proc macro for the host and
+// passes it with `--extern`, under both the inferred and an explicit crate name.
+
+// run-pass
+// edition:2018
+// aux-proc-macro: trivial-derive.rs
+// aux-proc-macro: renamed_derive=trivial-derive.rs
+
+#[derive(trivial_derive::Trivial)]
+struct Inferred;
+
+mod renamed {
+    #[derive(renamed_derive::Trivial)]
+    struct Renamed;
+
+    pub fn get() -> u32 {
+        TRIVIAL
+    }
+}
+
+fn main() {
+    assert_eq!(TRIVIAL, 42);
+    assert_eq!(renamed::get(), 42);
+}
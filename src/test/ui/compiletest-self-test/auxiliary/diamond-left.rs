@@ -0,0 +1,5 @@
+// aux-build: diamond-base.rs
+
+extern crate diamond_base;
+
+pub const LEFT: u32 = diamond_base::BASE + 10;
@@ -0,0 +1,7 @@
+// aux-build: diamond-left.rs
+// aux-build: diamond-right.rs
+
+extern crate diamond_left;
+extern crate diamond_right;
+
+pub const TOP: u32 = diamond_left::LEFT + diamond_right::RIGHT;
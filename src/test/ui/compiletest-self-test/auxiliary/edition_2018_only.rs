@@ -0,0 +1,12 @@
+// No `edition` directive on purpose: `aux-build-edition` in the test selects 2018.
+
+#[macro_export]
+macro_rules! make_future {
+    ($e:expr) => {
+        async move { $e }
+    };
+}
+
+pub async fn answer() -> u32 {
+    42
+}
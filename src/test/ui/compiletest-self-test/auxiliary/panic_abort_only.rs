@@ -0,0 +1,9 @@
+#![crate_type = "rlib"]
+#![feature(cfg_panic)]
+
+#[cfg(not(panic = "abort"))]
+compile_error!("this crate must be built with `-Cpanic=abort`");
+
+pub fn answer() -> u32 {
+    42
+}
@@ -0,0 +1,3 @@
+pub struct Version;
+
+pub const VERSION: u32 = 2;
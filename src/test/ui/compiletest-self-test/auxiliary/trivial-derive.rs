@@ -0,0 +1,10 @@
+// No `force-host`, `no-prefer-dynamic` or `crate_type` here: `aux-proc-macro` takes care of it.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+#[proc_macro_derive(Trivial)]
+pub fn derive_trivial(_input: TokenStream) -> TokenStream {
+    "const TRIVIAL: u32 = 42;".parse().unwrap()
+}
@@ -0,0 +1,5 @@
+#![crate_type = "rlib"]
+
+pub fn answer() -> u32 {
+    missing_answer()
+}
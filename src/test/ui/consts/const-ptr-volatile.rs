@@ -0,0 +1,26 @@
+// run-pass
+// Checks that volatile reads and writes act like plain ones during const evaluation.
+
+#![feature(const_ptr_volatile, const_mut_refs)]
+
+use std::ptr;
+
+static VALUE: u32 = 42;
+static COPY: u32 = unsafe { ptr::read_volatile(&VALUE) };
+
+const fn roundtrip(x: u32) -> u32 {
+    let mut y = 0;
+    unsafe {
+        ptr::write_volatile(&mut y, x);
+        (&y as *const u32).read_volatile()
+    }
+}
+
+const ROUNDTRIP: u32 = roundtrip(7);
+
+fn main() {
+    assert_eq!(COPY, 42);
+    assert_eq!(ROUNDTRIP, 7);
+    // The same function still works at runtime.
+    assert_eq!(roundtrip(7), 7);
+}
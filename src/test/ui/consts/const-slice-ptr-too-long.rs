@@ -0,0 +1,19 @@
+// Checks that raw slice pointers larger than `isize::MAX` bytes are rejected in constants.
+
+// dont-check-compiler-stderr
+// error-pattern: slice pointer length exceeds `isize::MAX` bytes
+
+#![feature(const_slice_from_raw_parts)]
+
+use std::ptr;
+
+// The largest slice that is still fine.
+const LONGEST: *const [u16] = ptr::slice_from_raw_parts(ptr::null(), isize::MAX as usize / 2);
+// Zero-sized elements never take up any space.
+const ZST: *const [()] = ptr::slice_from_raw_parts(ptr::null(), usize::MAX);
+
+const TOO_LONG: *const [u16] = ptr::slice_from_raw_parts(ptr::null(), isize::MAX as usize);
+
+fn main() {
+    let _ = (LONGEST, ZST, TOO_LONG);
+}
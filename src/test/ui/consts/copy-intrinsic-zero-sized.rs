@@ -0,0 +1,54 @@
+// run-pass
+// Zero-count copies and copies of zero-sized types are no-ops during const evaluation,
+// as they are at runtime, even between dangling (but non-null and aligned) pointers.
+#![feature(const_mut_refs, const_intrinsic_copy, const_ptr_offset)]
+use std::ptr::{self, NonNull};
+
+const COPY_ZERO_DANGLING: () = unsafe {
+    let src = NonNull::<u64>::dangling().as_ptr();
+    let dst = NonNull::<u64>::dangling().as_ptr();
+    ptr::copy(src, dst, 0);
+    ptr::copy_nonoverlapping(src, dst, 0);
+    (src as *const u64).copy_to(dst, 0);
+    (src as *const u64).copy_to_nonoverlapping(dst, 0);
+    dst.copy_from(src, 0);
+    dst.copy_from_nonoverlapping(src, 0);
+};
+
+const COPY_ZERO_INTO_LOCAL: u32 = unsafe {
+    let mut x = 7u32;
+    NonNull::<u32>::dangling().as_ptr().copy_to_nonoverlapping(&mut x, 0);
+    (&mut x as *mut u32).copy_from(NonNull::dangling().as_ptr(), 0);
+    x
+};
+
+const COPY_ONE_PAST_END: () = unsafe {
+    let src = [1u8, 2, 3];
+    let mut dst = [0u8; 3];
+    src.as_ptr().add(3).copy_to_nonoverlapping(dst.as_mut_ptr().add(3), 0);
+};
+
+const COPY_ZST: () = unsafe {
+    let src = NonNull::<()>::dangling().as_ptr();
+    let dst = NonNull::<()>::dangling().as_ptr();
+    ptr::copy(src, dst, usize::MAX);
+    // The pointers are equal, but a zero-sized copy never overlaps.
+    ptr::copy_nonoverlapping(src, dst, usize::MAX);
+    dst.copy_from(src, 1);
+    dst.copy_from_nonoverlapping(src, 1);
+};
+
+const COPY_ZST_ARRAY: () = unsafe {
+    let src = [[0u64; 0]; 4];
+    let mut dst = [[0u64; 0]; 4];
+    src.as_ptr().copy_to(dst.as_mut_ptr(), 4);
+    NonNull::<[u64; 0]>::dangling().as_ptr().copy_to_nonoverlapping(dst.as_mut_ptr(), 1000);
+};
+
+fn main() {
+    let () = COPY_ZERO_DANGLING;
+    assert_eq!(COPY_ZERO_INTO_LOCAL, 7);
+    let () = COPY_ONE_PAST_END;
+    let () = COPY_ZST;
+    let () = COPY_ZST_ARRAY;
+}
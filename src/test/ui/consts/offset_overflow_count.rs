@@ -0,0 +1,10 @@
+// Checks that an overflowing `add` during const evaluation reports the pointee size, the
+// element count and the resulting byte offset.
+
+// only-64bit
+
+#![feature(const_ptr_offset)]
+
+pub const ADD_OVERFLOW: *const u32 = unsafe { [0u32; 1].as_ptr().add(1 << 61) }; //~NOTE
+
+fn main() {}
@@ -2,6 +2,7 @@
 use std::ptr;
 
 // normalize-stderr-test "alloc\d+" -> "allocN"
+// normalize-stderr-test "-?\d+ elements = -?\d+ bytes" -> "N elements = M bytes"
 
 pub const BEFORE_START: *const u8 = unsafe { (&0u8 as *const u8).offset(-1) }; //~NOTE
 pub const AFTER_END: *const u8 = unsafe { (&0u8 as *const u8).offset(2) }; //~NOTE
@@ -14,3 +14,11 @@
 pub const _: () = assert!(std::ptr::null::<usize>().is_null());
 
 pub const _: () = assert!(!("foo" as *const str).is_null());
+
+static X: u8 = 7;
+
+pub static FROM_STATIC: () = assert!(!(&X as *const u8).is_null());
+
+pub static FROM_STATIC_SLICE: () = assert!(!(&[X, X] as *const [u8]).is_null());
+
+pub const _: () = assert!(std::ptr::null_mut::<u8>().is_null());
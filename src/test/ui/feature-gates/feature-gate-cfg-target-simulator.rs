@@ -0,0 +1,10 @@
+#[cfg(target_simulator)]
+//~^ ERROR `cfg(target_simulator)` is experimental and subject to change
+fn foo() -> bool { true }
+#[cfg(not(target_simulator))]
+//~^ ERROR `cfg(target_simulator)` is experimental and subject to change
+fn foo() -> bool { false }
+
+fn main() {
+    foo();
+}
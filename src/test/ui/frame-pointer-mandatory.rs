@@ -0,0 +1,13 @@
+// Tests that the compiler warns, and keeps the frame pointers, if the user tries to turn off
+// frame pointers on a target that requires them.
+//
+// check-pass
+// compile-flags: --target aarch64-apple-darwin -C force-frame-pointers=no
+// needs-llvm-components: aarch64
+
+#![feature(no_core, lang_items)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
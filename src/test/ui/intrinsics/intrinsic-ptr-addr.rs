@@ -0,0 +1,19 @@
+// run-pass
+
+#![feature(core_intrinsics, strict_provenance)]
+
+use std::intrinsics::ptr_addr;
+
+fn main() {
+    let arr = [1u16, 2, 3];
+    let ptr = arr.as_ptr();
+    assert_eq!(ptr_addr(ptr), ptr as usize);
+    assert_eq!(ptr.addr(), ptr as usize);
+    assert_eq!(ptr.wrapping_add(2).addr(), ptr.addr() + 4);
+
+    // Fat pointers only report their data address.
+    let slice: *const [u16] = &arr[1..];
+    assert_eq!(slice.addr(), ptr.addr() + 2);
+    let dynamic: *mut dyn std::fmt::Debug = &mut 5u8 as *mut u8;
+    assert_eq!(dynamic.addr(), dynamic.expose_addr());
+}
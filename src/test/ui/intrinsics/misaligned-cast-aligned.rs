@@ -0,0 +1,14 @@
+// run-fail
+// compile-flags: -C debug-assertions
+// error-pattern:attempt to access unaligned pointer
+// only-debug the check lives in libcore and needs debug assertions there
+// ignore-emscripten no processes
+
+#![feature(ptr_cast_aligned)]
+
+fn main() {
+    let buf = [0u32; 2];
+    // An `u32` can never sit at an odd address.
+    let odd = (buf.as_ptr() as *const u8).wrapping_add(1);
+    let _ptr = unsafe { odd.cast_aligned::<u32>() };
+}
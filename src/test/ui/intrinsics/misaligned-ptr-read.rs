@@ -0,0 +1,12 @@
+// run-fail
+// compile-flags: -C debug-assertions
+// error-pattern:attempt to access unaligned pointer
+// only-debug the check lives in libcore and needs debug assertions there
+// ignore-emscripten no processes
+
+fn main() {
+    let buf = [0u32; 2];
+    // An `u32` can never sit at an odd address.
+    let ptr = (buf.as_ptr() as *const u8).wrapping_add(1) as *const u32;
+    let _x = unsafe { ptr.read() };
+}
@@ -0,0 +1,16 @@
+// run-fail
+// compile-flags: -C debug-assertions
+// error-pattern:`sub_ptr` called with `origin` after `self`
+// only-debug the check lives in libcore and needs debug assertions there
+// ignore-emscripten no processes
+
+#![feature(nonnull_offset_from)]
+
+use std::ptr::NonNull;
+
+fn main() {
+    let mut buf = [0u32; 4];
+    let start = NonNull::from(&mut buf).cast::<u32>();
+    let end = unsafe { NonNull::new_unchecked(start.as_ptr().add(3)) };
+    let _len = unsafe { start.sub_ptr(end) };
+}
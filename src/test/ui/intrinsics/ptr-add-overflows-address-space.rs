@@ -0,0 +1,12 @@
+// run-fail
+// compile-flags: -C debug-assertions
+// error-pattern:`offset` called with a count that overflows the address space
+// only-debug the check lives in libcore and needs debug assertions there
+// ignore-emscripten no processes
+
+fn main() {
+    let buf = [0u32; 4];
+    // `usize::MAX / 4` elements of `u32` is more than `isize::MAX` bytes, which no offset
+    // may span. Leaving `buf` by a smaller count is not detected.
+    let _end = unsafe { buf.as_ptr().add(usize::MAX / 4) };
+}
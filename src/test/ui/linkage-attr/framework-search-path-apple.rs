@@ -0,0 +1,9 @@
+// Checks that framework search paths are accepted on Apple targets.
+
+// check-pass
+// compile-flags: --target x86_64-apple-darwin -L framework=foo
+// needs-llvm-components: x86
+
+#![feature(no_core)]
+#![no_core]
+#![crate_type = "rlib"]
@@ -0,0 +1,8 @@
+// Checks that framework search paths are rejected on targets without frameworks.
+
+// compile-flags: --target x86_64-unknown-linux-gnu -L framework=foo
+// needs-llvm-components: x86
+
+#![feature(no_core)]
+#![no_core]
+#![crate_type = "rlib"]
@@ -0,0 +1,8 @@
+// Checks that framework search paths are rejected on targets without frameworks.
+
+// compile-flags: --target wasm32-unknown-unknown -L framework=foo
+// needs-llvm-components: webassembly
+
+#![feature(no_core)]
+#![no_core]
+#![crate_type = "rlib"]
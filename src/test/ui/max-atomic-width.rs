@@ -0,0 +1,26 @@
+// Checks that `-Z max-atomic-width` lowers the size of the atomic operations that are
+// available, both to `cfg(target_has_atomic)` and to codegen of the atomic intrinsics.
+
+// build-fail
+// only-x86_64
+// compile-flags: -Z max-atomic-width=32
+
+#![feature(cfg_target_has_atomic, core_intrinsics)]
+#![crate_type = "rlib"]
+
+use std::intrinsics;
+
+#[cfg(not(target_has_atomic = "32"))]
+compile_error!("32-bit atomics should still be available");
+
+#[cfg(target_has_atomic = "64")]
+compile_error!("64-bit atomics should not be available");
+
+pub unsafe fn load_u32(p: &u32) -> u32 {
+    intrinsics::atomic_load(p)
+}
+
+pub unsafe fn load_u64(p: &u64) -> u64 {
+    intrinsics::atomic_load(p)
+    //~^ ERROR `atomic_load` intrinsic on `u64` exceeds the maximum atomic width
+}
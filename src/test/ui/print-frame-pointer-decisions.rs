@@ -0,0 +1,27 @@
+// Tests that `-Z print-frame-pointer-decisions` prints the frame pointer attribute of each
+// function, along with the policy and the setting it comes from.
+//
+// build-pass
+// ignore-pass
+// ^-- needed because `--pass check` does not emit the output needed.
+// revisions: flag target sanitizer
+// compile-flags: --target x86_64-unknown-linux-gnu -C codegen-units=1
+// compile-flags: -Z print-frame-pointer-decisions
+// needs-llvm-components: x86
+//[flag] compile-flags: -C force-frame-pointers=leaf
+//[sanitizer] compile-flags: -Z sanitizer=address
+
+#![feature(no_core, lang_items)]
+#![crate_type = "rlib"]
+#![no_core]
+
+#[lang = "sized"]
+trait Sized {}
+
+#[no_mangle]
+pub fn leaf() {}
+
+#[no_mangle]
+pub fn caller() {
+    leaf()
+}
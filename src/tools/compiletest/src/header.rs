@@ -51,7 +51,32 @@ pub fn from_reader<R: Read>(config: &Config, testfile: &Path, rdr: R) -> Self {
         let has_tsan = util::TSAN_SUPPORTED_TARGETS.contains(&&*config.target);
         let has_hwasan = util::HWASAN_SUPPORTED_TARGETS.contains(&&*config.target);
 
-        iter_header(testfile, None, rdr, &mut |ln| {
+        iter_header_with_revision(testfile, rdr, &mut |revision, line_number, ln| {
+            // Auxiliary crates are tracked for every revision, so that changing any of them
+            // invalidates the test. Only those of the revision under test are built, though.
+            if let Some(s) = config.parse_aux_build(ln) {
+                props.aux.push(s);
+            }
+
+            if let Some(ac) = config.parse_aux_crate(ln, testfile, line_number) {
+                props.aux_crate.push(ac);
+            }
+
+            if let Some((_, path)) = config.parse_aux_proc_macro(ln, testfile, line_number) {
+                props.aux.push(path);
+            }
+
+            if let Some(path) = config.parse_aux_build_fail(ln) {
+                // The expected output of the failing build lives next to it.
+                let stderr = Path::new(&path).with_extension("stderr");
+                props.aux.push(stderr.to_string_lossy().into_owned());
+                props.aux.push(path);
+            }
+
+            if revision.is_some() {
+                return;
+            }
+
             // we should check if any only-<platform> exists and if it exists
             // and does not matches the current platform, skip the test
             if !props.ignore {
@@ -128,14 +153,6 @@ pub fn from_reader<R: Read>(config: &Config, testfile: &Path, rdr: R) -> Self {
                 }
             }
 
-            if let Some(s) = config.parse_aux_build(ln) {
-                props.aux.push(s);
-            }
-
-            if let Some(ac) = config.parse_aux_crate(ln) {
-                props.aux_crate.push(ac);
-            }
-
             config.parse_and_update_revisions(ln, &mut props.revisions);
 
             props.should_fail = props.should_fail || config.parse_name_directive(ln, "should-fail");
@@ -277,8 +294,21 @@ pub struct TestProps {
     // we also check the auxiliary directory)
     pub aux_builds: Vec<String>,
     // Similar to `aux_builds`, but a list of NAME=somelib.rs of dependencies
-    // to build and pass with the `--extern` flag.
+    // to build and pass with the `--extern` flag. Each is built with `--crate-name`
+    // set to NAME (without any `priv:`-style options) if that differs from the
+    // crate name of the file.
     pub aux_crates: Vec<(String, String)>,
+    // Similar to `aux_crates`, but the crates are built as proc macros for the host.
+    pub aux_proc_macros: Vec<(String, String)>,
+    // Editions to build some of the `aux_builds` or `aux_crates` with, as a list of
+    // somelib.rs=EDITION. Overrides the `edition` directive of the auxiliary file itself.
+    pub aux_editions: Vec<(String, String)>,
+    // Extra flags to build some of the `aux_builds` or `aux_crates` with, as a list of
+    // somelib.rs=FLAGS. Appended after the `compile-flags` of the auxiliary file itself.
+    pub aux_build_flags: Vec<(String, String)>,
+    // Auxiliary crates that are expected to fail to compile. The test only passes if each of
+    // them is rejected (and, if there is a `.stderr` file next to it, with matching output).
+    pub aux_build_fails: Vec<String>,
     // Environment settings to use for compiling
     pub rustc_env: Vec<(String, String)>,
     // Environment variables to unset prior to compiling.
@@ -354,6 +384,10 @@ pub fn new() -> Self {
             pp_exact: None,
             aux_builds: vec![],
             aux_crates: vec![],
+            aux_proc_macros: vec![],
+            aux_editions: vec![],
+            aux_build_flags: vec![],
+            aux_build_fails: vec![],
             revisions: vec![],
             rustc_env: vec![],
             unset_rustc_env: vec![],
@@ -417,7 +451,7 @@ fn load_from(&mut self, testfile: &Path, cfg: Option<&str>, config: &Config) {
         if !testfile.is_dir() {
             let file = File::open(testfile).unwrap();
 
-            iter_header(testfile, cfg, file, &mut |ln| {
+            iter_header(testfile, cfg, file, &mut |line_number, ln| {
                 if let Some(ep) = config.parse_error_pattern(ln) {
                     self.error_patterns.push(ep);
                 }
@@ -488,10 +522,26 @@ fn load_from(&mut self, testfile: &Path, cfg: Option<&str>, config: &Config) {
                     self.aux_builds.push(ab);
                 }
 
-                if let Some(ac) = config.parse_aux_crate(ln) {
+                if let Some(ac) = config.parse_aux_crate(ln, testfile, line_number) {
                     self.aux_crates.push(ac);
                 }
 
+                if let Some(apm) = config.parse_aux_proc_macro(ln, testfile, line_number) {
+                    self.aux_proc_macros.push(apm);
+                }
+
+                if let Some(ae) = config.parse_aux_build_edition(ln) {
+                    self.aux_editions.push(ae);
+                }
+
+                if let Some(abf) = config.parse_aux_build_flags(ln) {
+                    self.aux_build_flags.push(abf);
+                }
+
+                if let Some(abf) = config.parse_aux_build_fail(ln) {
+                    self.aux_build_fails.push(abf);
+                }
+
                 if let Some(ee) = config.parse_env(ln, "exec-env") {
                     self.exec_env.push(ee);
                 }
@@ -646,7 +696,29 @@ pub fn local_pass_mode(&self) -> Option<PassMode> {
     }
 }
 
-fn iter_header<R: Read>(testfile: &Path, cfg: Option<&str>, rdr: R, it: &mut dyn FnMut(&str)) {
+/// Calls `it` with the 1-based line number and contents of every directive in `testfile` that
+/// applies to the revision `cfg`.
+fn iter_header<R: Read>(
+    testfile: &Path,
+    cfg: Option<&str>,
+    rdr: R,
+    it: &mut dyn FnMut(usize, &str),
+) {
+    iter_header_with_revision(testfile, rdr, &mut |revision, line_number, ln| {
+        // A directive like `//[foo] aux-build: foo.rs` is specific to revision `foo`.
+        if revision.is_none() || revision == cfg {
+            it(line_number, ln);
+        }
+    });
+}
+
+/// Calls `it` for every directive in `testfile`, along with the revision it is specific to, if
+/// any.
+fn iter_header_with_revision<R: Read>(
+    testfile: &Path,
+    rdr: R,
+    it: &mut dyn FnMut(Option<&str>, usize, &str),
+) {
     if testfile.is_dir() {
         return;
     }
@@ -655,8 +727,10 @@ fn iter_header<R: Read>(testfile: &Path, cfg: Option<&str>, rdr: R, it: &mut dyn
 
     let mut rdr = BufReader::new(rdr);
     let mut ln = String::new();
+    let mut line_number = 0;
 
     loop {
+        line_number += 1;
         ln.clear();
         if rdr.read_line(&mut ln).unwrap() == 0 {
             break;
@@ -673,18 +747,12 @@ fn iter_header<R: Read>(testfile: &Path, cfg: Option<&str>, rdr: R, it: &mut dyn
             if let Some(close_brace) = ln.find(']') {
                 let open_brace = ln.find('[').unwrap();
                 let lncfg = &ln[open_brace + 1..close_brace];
-                let matches = match cfg {
-                    Some(s) => s == &lncfg[..],
-                    None => false,
-                };
-                if matches {
-                    it(ln[(close_brace + 1)..].trim_start());
-                }
+                it(Some(lncfg), line_number, ln[(close_brace + 1)..].trim_start());
             } else {
                 panic!("malformed condition directive: expected `{}[foo]`, found `{}`", comment, ln)
             }
         } else if ln.starts_with(comment) {
-            it(ln[comment.len()..].trim_start());
+            it(None, line_number, ln[comment.len()..].trim_start());
         }
     }
 }
@@ -705,13 +773,62 @@ fn parse_aux_build(&self, line: &str) -> Option<String> {
         self.parse_name_value_directive(line, "aux-build").map(|r| r.trim().to_string())
     }
 
-    fn parse_aux_crate(&self, line: &str) -> Option<(String, String)> {
+    /// Parses an `aux-crate: NAME=path` directive, panicking with the location of the
+    /// directive if it is malformed.
+    fn parse_aux_crate(
+        &self,
+        line: &str,
+        testfile: &Path,
+        line_number: usize,
+    ) -> Option<(String, String)> {
         self.parse_name_value_directive(line, "aux-crate").map(|r| {
+            parse_aux_crate_value("aux-crate", &r)
+                .unwrap_or_else(|e| panic!("{}:{}: {}", testfile.display(), line_number, e))
+        })
+    }
+
+    /// Parses an `aux-proc-macro: [NAME=]path` directive. Without an explicit `NAME`, the
+    /// crate is passed under the name inferred from its file name.
+    fn parse_aux_proc_macro(
+        &self,
+        line: &str,
+        testfile: &Path,
+        line_number: usize,
+    ) -> Option<(String, String)> {
+        self.parse_name_value_directive(line, "aux-proc-macro").map(|r| {
+            let r = r.trim();
+            let parsed = if r.contains('=') {
+                parse_aux_crate_value("aux-proc-macro", r)
+            } else if r.is_empty() {
+                Err("missing aux-proc-macro value (e.g. my_derive.rs)".to_string())
+            } else {
+                let name = Path::new(r).file_stem().unwrap().to_str().unwrap();
+                Ok((name.replace('-', "_"), r.to_string()))
+            };
+            parsed.unwrap_or_else(|e| panic!("{}:{}: {}", testfile.display(), line_number, e))
+        })
+    }
+
+    fn parse_aux_build_fail(&self, line: &str) -> Option<String> {
+        self.parse_name_value_directive(line, "aux-build-fail").map(|r| r.trim().to_string())
+    }
+
+    fn parse_aux_build_edition(&self, line: &str) -> Option<(String, String)> {
+        self.parse_name_value_directive(line, "aux-build-edition").map(|r| {
             let mut parts = r.trim().splitn(2, '=');
-            (
-                parts.next().expect("missing aux-crate name (e.g. log=log.rs)").to_string(),
-                parts.next().expect("missing aux-crate value (e.g. log=log.rs)").to_string(),
-            )
+            let file = parts.next().expect("missing aux-build-edition file (e.g. foo.rs=2018)");
+            let edition = parts.next().expect("missing aux-build-edition value (e.g. foo.rs=2018)");
+            (file.to_string(), edition.to_string())
+        })
+    }
+
+    fn parse_aux_build_flags(&self, line: &str) -> Option<(String, String)> {
+        self.parse_name_value_directive(line, "aux-build-flags").map(|r| {
+            // Only split on the first `=`, flags like `-Cpanic=abort` contain one too.
+            let mut parts = r.trim().splitn(2, '=');
+            let file = parts.next().expect("missing aux-build-flags file (e.g. foo.rs=-Cfoo)");
+            let flags = parts.next().expect("missing aux-build-flags value (e.g. foo.rs=-Cfoo)");
+            (file.to_string(), flags.to_string())
         })
     }
 
@@ -939,6 +1056,30 @@ fn parse_edition(&self, line: &str) -> Option<String> {
     }
 }
 
+/// Splits the value of an `aux-crate` (or `aux-proc-macro`) directive into the crate name and
+/// the path of its source.
+///
+/// Only the first `=` separates the two, so the path itself may contain further `=`s.
+fn parse_aux_crate_value(directive: &str, value: &str) -> Result<(String, String), String> {
+    let value = value.trim();
+    let (name, path) = match value.find('=') {
+        Some(eq) => (&value[..eq], &value[eq + 1..]),
+        None => {
+            return Err(format!(
+                "malformed {} directive: expected `NAME=path` (e.g. log=log.rs), found `{}`",
+                directive, value
+            ));
+        }
+    };
+    if name.is_empty() {
+        return Err(format!("missing {} name (e.g. log=log.rs)", directive));
+    }
+    if path.is_empty() {
+        return Err(format!("missing {} value (e.g. log=log.rs)", directive));
+    }
+    Ok((name.to_string(), path.to_string()))
+}
+
 fn expand_variables(mut value: String, config: &Config) -> String {
     const CWD: &str = "{{cwd}}";
     const SRC_BASE: &str = "{{src-base}}";
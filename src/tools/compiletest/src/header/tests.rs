@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::common::{Config, Debugger};
-use crate::header::{parse_normalization_string, EarlyProps};
+use crate::header::{parse_aux_crate_value, parse_normalization_string, EarlyProps};
 
 #[test]
 fn test_parse_normalization_string() {
@@ -106,6 +106,76 @@ fn aux_build() {
     );
 }
 
+#[test]
+fn aux_build_per_revision() {
+    let config = config();
+
+    // Early props track the auxiliary crates of every revision.
+    assert_eq!(
+        parse_rs(
+            &config,
+            r"
+        // revisions: a b
+        //[a] aux-build: a.rs
+        //[b] aux-build: b.rs
+        // aux-build: common.rs
+        "
+        )
+        .aux,
+        vec!["a.rs", "b.rs", "common.rs"],
+    );
+}
+
+#[test]
+fn aux_proc_macro() {
+    let config = config();
+    let file = Path::new("a.rs");
+
+    assert_eq!(
+        config.parse_aux_proc_macro("aux-proc-macro: my-derive.rs", file, 1),
+        Some(("my_derive".to_owned(), "my-derive.rs".to_owned())),
+    );
+    assert_eq!(
+        config.parse_aux_proc_macro("aux-proc-macro: renamed=my-derive.rs", file, 1),
+        Some(("renamed".to_owned(), "my-derive.rs".to_owned())),
+    );
+}
+
+#[test]
+fn aux_build_edition() {
+    let config = config();
+
+    assert_eq!(
+        config.parse_aux_build_edition("aux-build-edition: foo.rs=2018"),
+        Some(("foo.rs".to_owned(), "2018".to_owned())),
+    );
+    assert_eq!(config.parse_aux_build_edition("aux-build: foo.rs"), None);
+}
+
+#[test]
+fn aux_build_flags() {
+    let config = config();
+
+    assert_eq!(
+        config.parse_aux_build_flags("aux-build-flags: foo.rs=-Cpanic=abort"),
+        Some(("foo.rs".to_owned(), "-Cpanic=abort".to_owned())),
+    );
+    assert_eq!(
+        config.parse_aux_build_flags("aux-build-flags: foo.rs=-Ctarget-feature=+avx -O"),
+        Some(("foo.rs".to_owned(), "-Ctarget-feature=+avx -O".to_owned())),
+    );
+    assert_eq!(config.parse_aux_build_flags("aux-build: foo.rs"), None);
+}
+
+#[test]
+fn aux_build_fail() {
+    let config = config();
+
+    assert_eq!(config.parse_aux_build_fail("aux-build-fail: bad.rs"), Some("bad.rs".to_owned()));
+    assert_eq!(config.parse_aux_build_fail("aux-build: bad.rs"), None);
+    assert_eq!(config.parse_aux_build("aux-build-fail: bad.rs"), None);
+}
+
 #[test]
 fn no_system_llvm() {
     let mut config = config();
@@ -255,3 +325,45 @@ fn test_duplicate_revisions() {
     let config = config();
     parse_rs(&config, "// revisions: rpass1 rpass1");
 }
+
+#[test]
+fn aux_crate() {
+    assert_eq!(
+        parse_aux_crate_value("aux-crate", "log=log.rs"),
+        Ok(("log".to_owned(), "log.rs".to_owned()))
+    );
+    // Only the first `=` separates the name from the path.
+    assert_eq!(
+        parse_aux_crate_value("aux-crate", " a=b=c.rs "),
+        Ok(("a".to_owned(), "b=c.rs".to_owned()))
+    );
+}
+
+#[test]
+fn aux_crate_missing_value() {
+    assert_eq!(
+        parse_aux_crate_value("aux-crate", "log="),
+        Err("missing aux-crate value (e.g. log=log.rs)".to_owned())
+    );
+    assert_eq!(
+        parse_aux_crate_value("aux-crate", "=log.rs"),
+        Err("missing aux-crate name (e.g. log=log.rs)".to_owned())
+    );
+}
+
+#[test]
+fn aux_crate_missing_separator() {
+    assert_eq!(
+        parse_aux_crate_value("aux-crate", "log.rs"),
+        Err("malformed aux-crate directive: expected `NAME=path` (e.g. log=log.rs), \
+             found `log.rs`"
+            .to_owned())
+    );
+}
+
+#[test]
+#[should_panic(expected = "a.rs:2: missing aux-crate value (e.g. log=log.rs)")]
+fn aux_crate_error_location() {
+    let config = config();
+    parse_rs(&config, "// check-pass\n// aux-crate: log=");
+}
@@ -687,7 +687,11 @@ fn is_up_to_date(
 
     for aux in &props.aux {
         let path = testpaths.file.parent().unwrap().join("auxiliary").join(aux);
-        inputs.add_path(&path);
+        // `aux-build` accepts glob patterns, so stamp every file they match.
+        match glob::glob(&path.to_string_lossy()) {
+            Ok(paths) => paths.filter_map(Result::ok).for_each(|path| inputs.add_path(&path)),
+            Err(_) => inputs.add_path(&path),
+        }
     }
 
     // UI test files.
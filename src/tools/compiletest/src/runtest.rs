@@ -100,6 +100,30 @@ pub fn get_lib_name(lib: &str, dylib: bool) -> String {
     }
 }
 
+/// The name rustc gives the crate built from the auxiliary file `aux_path`, absent any
+/// `--crate-name` flag or `#![crate_name]` attribute.
+fn default_aux_crate_name(aux_path: &str) -> String {
+    Path::new(aux_path).file_stem().unwrap().to_str().unwrap().replace('-', "_")
+}
+
+/// The crate name to build the `aux-crate` dependency `extern_name=aux_path` with, if it is
+/// not the default one. Only when another of the `aux_crates` comes from a different source
+/// with the same default crate name is it built under its own extern name (without any
+/// `priv:`-style options), which gives the two builds distinct crate identities so that they do
+/// not clash in the test. Aliases of a single source stay one crate.
+fn aux_crate_name(
+    extern_name: &str,
+    aux_path: &str,
+    aux_crates: &[(String, String)],
+) -> Option<String> {
+    let default_name = default_aux_crate_name(aux_path);
+    let clashes = aux_crates
+        .iter()
+        .any(|(_, path)| path != aux_path && default_aux_crate_name(path) == default_name);
+    let crate_name = extern_name.rsplit(':').next().unwrap();
+    if !clashes || crate_name == default_name { None } else { Some(crate_name.to_string()) }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum DiffLine {
     Context(String),
@@ -319,6 +343,28 @@ enum WillExecute {
     No,
 }
 
+/// What kind of crate should an auxiliary build produce?
+#[derive(Copy, Clone)]
+enum AuxType {
+    /// A library, built as a dylib where the target supports it.
+    Lib,
+    /// A proc macro, always built for the host.
+    ProcMacro,
+}
+
+/// An auxiliary crate to build: the file it is built from, and the crate name to build it with
+/// if that is not the default one.
+type AuxKey = (String, Option<String>);
+
+/// Is an auxiliary build expected to compile?
+#[derive(Copy, Clone)]
+enum AuxOutcome {
+    /// An `aux-build`, `aux-crate` or `aux-proc-macro` dependency of the test.
+    Pass,
+    /// An `aux-build-fail` crate, which must be rejected by the compiler.
+    Fail,
+}
+
 /// Should `--emit metadata` be used?
 #[derive(Copy, Clone)]
 enum EmitMetadata {
@@ -1735,26 +1781,142 @@ fn is_vxworks_pure_dynamic(&self) -> bool {
 
     fn build_all_auxiliary(&self, rustc: &mut Command) -> PathBuf {
         let aux_dir = self.aux_output_dir_name();
+        let auxiliaries = self.ordered_auxiliaries();
 
-        if !self.props.aux_builds.is_empty() {
+        if !auxiliaries.is_empty() || !self.props.aux_build_fails.is_empty() {
             let _ = fs::remove_dir_all(&aux_dir);
             create_dir_all(&aux_dir).unwrap();
         }
 
-        for rel_ab in &self.props.aux_builds {
-            self.build_auxiliary(rel_ab, &aux_dir);
+        let mut built = HashMap::new();
+        for (aux_key, aux_type) in auxiliaries {
+            let (aux_path, crate_name) = &aux_key;
+            let is_dylib = self.build_auxiliary(
+                aux_path,
+                crate_name.as_deref(),
+                &aux_dir,
+                aux_type,
+                AuxOutcome::Pass,
+                &built,
+            );
+            built.insert(aux_key, is_dylib);
         }
 
-        for (aux_name, aux_path) in &self.props.aux_crates {
-            let is_dylib = self.build_auxiliary(&aux_path, &aux_dir);
-            let lib_name =
-                get_lib_name(&aux_path.trim_end_matches(".rs").replace('-', "_"), is_dylib);
-            rustc.arg("--extern").arg(format!("{}={}/{}", aux_name, aux_dir.display(), lib_name));
+        // Nothing can depend on these, so they are checked after everything else is built.
+        for aux_path in &self.props.aux_build_fails {
+            self.build_auxiliary(aux_path, None, &aux_dir, AuxType::Lib, AuxOutcome::Fail, &built);
         }
 
+        self.add_aux_externs(self.props, rustc, &aux_dir, &built);
+
         aux_dir
     }
 
+    /// Lists the auxiliary crates to build for this test. Each crate is listed once, after all
+    /// of the auxiliary crates it depends on through its own `aux-build`, `aux-crate` and
+    /// `aux-proc-macro` directives. A file is only listed several times if it is an `aux-crate`
+    /// under several crate names.
+    ///
+    /// `aux-build-fail` files are not listed themselves, but their dependencies are.
+    fn ordered_auxiliaries(&self) -> Vec<(AuxKey, AuxType)> {
+        let mut ordered = Vec::new();
+        self.visit_auxiliaries(self.props, &mut Vec::new(), &mut ordered);
+        for aux_path in &self.props.aux_build_fails {
+            let aux_testpaths = self.compute_aux_test_paths(aux_path);
+            let aux_props =
+                self.props.from_aux_file(&aux_testpaths.file, self.revision, self.config);
+            self.visit_auxiliaries(&aux_props, &mut vec![aux_path.clone()], &mut ordered);
+        }
+        ordered
+    }
+
+    fn visit_auxiliaries(
+        &self,
+        props: &TestProps,
+        visiting: &mut Vec<String>,
+        ordered: &mut Vec<(AuxKey, AuxType)>,
+    ) {
+        let aux_builds: Vec<_> =
+            props.aux_builds.iter().flat_map(|path| self.expand_aux_build(path)).collect();
+        let aux_builds = aux_builds.iter().map(|path| (path, None, AuxType::Lib));
+        let aux_crates = props.aux_crates.iter().map(|(name, path)| {
+            (path, aux_crate_name(name, path, &props.aux_crates), AuxType::Lib)
+        });
+        let aux_proc_macros =
+            props.aux_proc_macros.iter().map(|(_, path)| (path, None, AuxType::ProcMacro));
+
+        for (aux_path, crate_name, aux_type) in aux_builds.chain(aux_crates).chain(aux_proc_macros)
+        {
+            let aux_key = (aux_path.clone(), crate_name);
+            if ordered.iter().any(|(key, _)| *key == aux_key) {
+                continue;
+            }
+            if visiting.contains(aux_path) {
+                self.fatal(&format!(
+                    "auxiliary crates depend on each other: {} -> {}",
+                    visiting.join(" -> "),
+                    aux_path
+                ));
+            }
+
+            // Auxiliary files refer to each other relative to the auxiliary directory of the
+            // test, so their paths resolve the same way as the test's own.
+            let aux_testpaths = self.compute_aux_test_paths(aux_path);
+            let aux_props =
+                self.props.from_aux_file(&aux_testpaths.file, self.revision, self.config);
+            visiting.push(aux_path.clone());
+            self.visit_auxiliaries(&aux_props, visiting, ordered);
+            visiting.pop();
+
+            ordered.push((aux_key, aux_type));
+        }
+    }
+
+    /// Expands an `aux-build` path with glob patterns (like `helpers/*.rs`) into the matching
+    /// files of the auxiliary directory, in sorted order. Other paths are returned as is.
+    fn expand_aux_build(&self, aux_path: &str) -> Vec<String> {
+        if !aux_path.contains(&['*', '?', '['][..]) {
+            return vec![aux_path.to_string()];
+        }
+
+        let aux_dir =
+            self.testpaths.file.parent().expect("test file path has no parent").join("auxiliary");
+        let pattern = aux_dir.join(aux_path);
+        let matches = glob(&pattern.to_string_lossy()).unwrap_or_else(|e| {
+            self.fatal(&format!("invalid aux-build pattern `{}`: {}", aux_path, e))
+        });
+        let mut paths: Vec<String> = matches
+            .filter_map(Result::ok)
+            .map(|path| path.strip_prefix(&aux_dir).unwrap().to_string_lossy().into_owned())
+            .collect();
+        if paths.is_empty() {
+            self.fatal(&format!("aux-build pattern `{}` does not match any file", aux_path));
+        }
+        paths.sort();
+        paths
+    }
+
+    /// Passes the `aux-crate` and `aux-proc-macro` dependencies of `props` with `--extern`.
+    /// `built` records whether each of the (already built) auxiliary crates is a dylib.
+    fn add_aux_externs(
+        &self,
+        props: &TestProps,
+        rustc: &mut Command,
+        aux_dir: &Path,
+        built: &HashMap<AuxKey, bool>,
+    ) {
+        let aux_crates = props
+            .aux_crates
+            .iter()
+            .map(|(name, path)| (name, path, aux_crate_name(name, path, &props.aux_crates)));
+        let aux_proc_macros = props.aux_proc_macros.iter().map(|(name, path)| (name, path, None));
+        for (aux_name, aux_path, crate_name) in aux_crates.chain(aux_proc_macros) {
+            let lib = crate_name.clone().unwrap_or_else(|| default_aux_crate_name(aux_path));
+            let lib_name = get_lib_name(&lib, built[&(aux_path.clone(), crate_name)]);
+            rustc.arg("--extern").arg(format!("{}={}/{}", aux_name, aux_dir.display(), lib_name));
+        }
+    }
+
     fn compose_and_run_compiler(&self, mut rustc: Command, input: Option<String>) -> ProcRes {
         let aux_dir = self.build_all_auxiliary(&mut rustc);
         self.props.unset_rustc_env.clone().iter().fold(&mut rustc, |rustc, v| rustc.env_remove(v));
@@ -1767,12 +1929,36 @@ fn compose_and_run_compiler(&self, mut rustc: Command, input: Option<String>) ->
         )
     }
 
-    /// Builds an aux dependency.
+    /// Builds an aux dependency, as `crate_name` if that is given. All of its own auxiliary
+    /// dependencies must already be recorded in `built`.
     ///
     /// Returns whether or not it is a dylib.
-    fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
+    fn build_auxiliary(
+        &self,
+        source_path: &str,
+        crate_name: Option<&str>,
+        aux_dir: &Path,
+        aux_type: AuxType,
+        outcome: AuxOutcome,
+        built: &HashMap<AuxKey, bool>,
+    ) -> bool {
         let aux_testpaths = self.compute_aux_test_paths(source_path);
-        let aux_props = self.props.from_aux_file(&aux_testpaths.file, self.revision, self.config);
+        let mut aux_props =
+            self.props.from_aux_file(&aux_testpaths.file, self.revision, self.config);
+        if let AuxType::ProcMacro = aux_type {
+            aux_props.force_host = true;
+        }
+        if let Some((_, edition)) =
+            self.props.aux_editions.iter().find(|(file, _)| file == source_path)
+        {
+            aux_props.compile_flags.retain(|flag| !flag.starts_with("--edition="));
+            aux_props.compile_flags.push(format!("--edition={}", edition));
+        }
+        for (file, flags) in &self.props.aux_build_flags {
+            if file == source_path {
+                aux_props.compile_flags.extend(flags.split_whitespace().map(str::to_owned));
+            }
+        }
         let aux_output = TargetLocation::ThisDirectory(self.aux_output_dir_name());
         let aux_cx = TestCx {
             config: self.config,
@@ -1791,7 +1977,10 @@ fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
         }
         aux_rustc.envs(aux_props.rustc_env.clone());
 
-        let (dylib, crate_type) = if aux_props.no_prefer_dynamic {
+        let (dylib, crate_type) = if let AuxType::ProcMacro = aux_type {
+            // Proc macros are always dynamic libraries for the host.
+            (true, Some("proc-macro"))
+        } else if aux_props.no_prefer_dynamic {
             (true, None)
         } else if self.config.target.contains("emscripten")
             || (self.config.target.contains("musl")
@@ -1819,8 +2008,12 @@ fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
         if let Some(crate_type) = crate_type {
             aux_rustc.args(&["--crate-type", crate_type]);
         }
+        if let Some(crate_name) = crate_name {
+            aux_rustc.args(&["--crate-name", crate_name]);
+        }
 
         aux_rustc.arg("-L").arg(&aux_dir);
+        self.add_aux_externs(&aux_props, &mut aux_rustc, aux_dir, built);
 
         let auxres = aux_cx.compose_and_run(
             aux_rustc,
@@ -1828,16 +2021,63 @@ fn build_auxiliary(&self, source_path: &str, aux_dir: &Path) -> bool {
             Some(aux_dir.to_str().unwrap()),
             None,
         );
-        if !auxres.status.success() {
+        match (outcome, auxres.status.success()) {
+            (AuxOutcome::Pass, true) => {}
+            (AuxOutcome::Pass, false) => {
+                self.fatal_proc_rec(
+                    &format!(
+                        "auxiliary build of {:?} failed to compile: ",
+                        aux_testpaths.file.display()
+                    ),
+                    &auxres,
+                );
+            }
+            (AuxOutcome::Fail, true) => {
+                self.fatal_proc_rec(
+                    &format!(
+                        "auxiliary build of {:?} was expected to fail, but compiled successfully: ",
+                        aux_testpaths.file.display()
+                    ),
+                    &auxres,
+                );
+            }
+            (AuxOutcome::Fail, false) => aux_cx.check_aux_build_failure(&auxres),
+        }
+        dylib
+    }
+
+    /// Compares the output of a rejected `aux-build-fail` crate against the `.stderr` file next
+    /// to it, if there is one.
+    fn check_aux_build_failure(&self, auxres: &ProcRes) {
+        let expected_path = expected_output_path(
+            self.testpaths,
+            self.revision,
+            &self.config.compare_mode,
+            UI_STDERR,
+        );
+        if !expected_path.exists() {
+            return;
+        }
+        let expected =
+            self.load_expected_output_from_path(&expected_path).unwrap_or_else(|e| self.fatal(&e));
+
+        let explicit = self.props.compile_flags.iter().any(|s| s.contains("--error-format"));
+        let stderr = if explicit || !matches!(self.config.mode, Ui) {
+            auxres.stderr.clone()
+        } else {
+            json::extract_rendered(&auxres.stderr)
+        };
+        let normalized = self.normalize_output(&stderr, &self.props.normalize_stderr);
+
+        if self.compare_output(UI_STDERR, &normalized, &expected) > 0 {
             self.fatal_proc_rec(
                 &format!(
-                    "auxiliary build of {:?} failed to compile: ",
-                    aux_testpaths.file.display()
+                    "auxiliary build of {:?} failed with unexpected output: ",
+                    self.testpaths.file.display()
                 ),
-                &auxres,
+                auxres,
             );
         }
-        dylib
     }
 
     fn compose_and_run(
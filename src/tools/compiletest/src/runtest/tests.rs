@@ -48,3 +48,26 @@ fn normalize_platform_differences() {
         r#"println!("test\ntest")"#,
     );
 }
+
+#[test]
+fn aux_crate_names() {
+    let crates = |list: &[(&str, &str)]| -> Vec<(String, String)> {
+        list.iter().map(|&(name, path)| (name.to_string(), path.to_string())).collect()
+    };
+
+    let single = crates(&[("log", "log.rs"), ("log2", "log.rs")]);
+    assert_eq!(aux_crate_name("log", "log.rs", &single), None);
+    assert_eq!(aux_crate_name("log2", "log.rs", &single), None);
+
+    let clashing = crates(&[("log_v1", "log-v1/log.rs"), ("log_v2", "log-v2/log.rs")]);
+    assert_eq!(aux_crate_name("log_v1", "log-v1/log.rs", &clashing), Some("log_v1".to_string()));
+    assert_eq!(aux_crate_name("log", "log-v2/log.rs", &clashing), None);
+    assert_eq!(
+        aux_crate_name("priv,noprelude:log2", "log-v2/log.rs", &clashing),
+        Some("log2".to_string())
+    );
+
+    let dashed = crates(&[("my_log", "my-log.rs"), ("my_log2", "v2/my_log.rs")]);
+    assert_eq!(aux_crate_name("my_log", "my-log.rs", &dashed), None);
+    assert_eq!(aux_crate_name("priv:my_log", "my-log.rs", &dashed), None);
+}
@@ -46,6 +46,7 @@ macro_rules! tidy_error {
 pub mod extdeps;
 pub mod features;
 pub mod pal;
+pub mod ptr_parity;
 pub mod style;
 pub mod ui_tests;
 pub mod unit_tests;
@@ -69,6 +69,7 @@ macro_rules! check {
 
         // Checks that only make sense for the std libs.
         check!(pal, &library_path);
+        check!(ptr_parity, &library_path);
 
         // Checks that need to be done for both the compiler and std libraries.
         check!(unit_tests, &src_path);
@@ -0,0 +1,101 @@
+//! Tidy check to ensure that the inherent methods of `*const T` and `*mut T` stay in sync.
+//!
+//! Every public method on one raw pointer type should have a twin of the same name on the other,
+//! except for those listed below.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Methods that only exist on `*mut T` and `*mut [T]`, because they write through the pointer,
+/// hand out mutable access, or are the `_mut` spelling of a `*const` method.
+const MUT_ONLY: &[&str] = &[
+    "as_mut",
+    "as_mut_ptr",
+    "as_uninit_mut",
+    "as_uninit_slice_mut",
+    "copy_from",
+    "copy_from_nonoverlapping",
+    "copy_within",
+    "drop_in_place",
+    "get_unchecked_mut",
+    "replace",
+    "swap",
+    "write",
+    "write_bytes",
+    "write_unaligned",
+    "write_unaligned_at",
+    "write_volatile",
+];
+
+/// Methods that only exist on `*const T` and `*const [T]`, because `*mut` spells them with a
+/// `_mut` suffix.
+const CONST_ONLY: &[&str] = &["as_ptr", "get_unchecked"];
+
+pub fn check(library_path: &Path, bad: &mut bool) {
+    let ptr_dir = library_path.join("core/src/ptr");
+    let const_methods = inherent_methods(&ptr_dir.join("const_ptr.rs"), "*const ");
+    let mut_methods = inherent_methods(&ptr_dir.join("mut_ptr.rs"), "*mut ");
+
+    let impls: BTreeSet<_> = const_methods.keys().chain(mut_methods.keys()).collect();
+    let empty = BTreeSet::new();
+    for self_ty in impls {
+        let on_const = const_methods.get(self_ty).unwrap_or(&empty);
+        let on_mut = mut_methods.get(self_ty).unwrap_or(&empty);
+        for name in on_const.difference(on_mut) {
+            if !CONST_ONLY.contains(&name.as_str()) {
+                tidy_error!(
+                    bad,
+                    "`{}` is a method of `impl{}` in const_ptr.rs but not in mut_ptr.rs",
+                    name,
+                    self_ty.replace("*ptr", "*const"),
+                );
+            }
+        }
+        for name in on_mut.difference(on_const) {
+            if !MUT_ONLY.contains(&name.as_str()) {
+                tidy_error!(
+                    bad,
+                    "`{}` is a method of `impl{}` in mut_ptr.rs but not in const_ptr.rs",
+                    name,
+                    self_ty.replace("*ptr", "*mut"),
+                );
+            }
+        }
+    }
+}
+
+/// Collects the names of the `pub` methods in each inherent impl of `file`, keyed by the impl's
+/// self type with the pointer kind `ptr_kind` replaced by `*ptr `.
+fn inherent_methods(file: &Path, ptr_kind: &str) -> BTreeMap<String, BTreeSet<String>> {
+    let contents = t!(fs::read_to_string(file), file);
+    let mut methods: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut current = None;
+    for line in contents.lines() {
+        if let Some(header) = line.strip_prefix("impl") {
+            // Trait impls have no bearing on the inherent API.
+            current = if header.contains(" for ") {
+                None
+            } else {
+                let self_ty = header.trim_end_matches('{').trim();
+                Some(self_ty.replacen(ptr_kind, "*ptr ", 1))
+            };
+            continue;
+        }
+        let self_ty = match &current {
+            Some(self_ty) => self_ty,
+            None => continue,
+        };
+        let item = match line.strip_prefix("    pub ") {
+            Some(item) => item,
+            None => continue,
+        };
+        let item = item.trim_start_matches("const ").trim_start_matches("unsafe ");
+        if let Some(signature) = item.strip_prefix("fn ") {
+            let name: String =
+                signature.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect();
+            methods.entry(self_ty.clone()).or_default().insert(name);
+        }
+    }
+    methods
+}